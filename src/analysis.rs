@@ -0,0 +1,239 @@
+use crate::types::{Chord, ChordQuality, HarmonicFunction, Interval, Key, Mode, NoteName, Scale};
+
+/// A cadence formed by the last two chords of a phrase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cadence {
+    /// Dominant to tonic (V–I)
+    Authentic,
+    /// Subdominant to tonic (IV–I)
+    Plagal,
+    /// Any chord to the chord on the fifth degree (ii–V, I–V)
+    Half,
+    /// Dominant to submediant (V–vi)
+    Deceptive,
+}
+
+/// Detects the cadence at the end of a chord progression
+///
+/// Only the final two chords are considered, classified by their harmonic
+/// function in `key`. Returns `None` for progressions shorter than two chords
+/// or endings that don't form one of the recognised cadences.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Chord, Scale, ScaleType, SeventhType};
+/// use chordy::analysis::{detect_cadence, Cadence};
+///
+/// let n = |letter| NoteName::new(letter, Accidental::Natural);
+/// let c_major = Scale::new(n(Letter::C), ScaleType::Major);
+/// let g7 = Chord::seventh(n(Letter::G), SeventhType::Dominant);
+/// let chords = [g7, Chord::major(n(Letter::C))];
+/// assert_eq!(detect_cadence(&chords, &c_major), Some(Cadence::Authentic));
+/// ```
+pub fn detect_cadence(chords: &[Chord], key: &Scale) -> Option<Cadence> {
+    let [.., penultimate, last] = chords else {
+        return None;
+    };
+
+    let from = key.harmonic_function(penultimate);
+    let to = key.harmonic_function(last)?;
+    let to_degree = key.letter_degree_of(&last.root())?;
+
+    match (from, to, to_degree) {
+        (Some(HarmonicFunction::Dominant), HarmonicFunction::Tonic, 1) => Some(Cadence::Authentic),
+        (Some(HarmonicFunction::Dominant), HarmonicFunction::Tonic, 6) => Some(Cadence::Deceptive),
+        (Some(HarmonicFunction::Subdominant), HarmonicFunction::Tonic, 1) => Some(Cadence::Plagal),
+        (_, HarmonicFunction::Dominant, 5) => Some(Cadence::Half),
+        _ => None,
+    }
+}
+
+/// Number of consecutive chords used to judge the key at each point
+const MODULATION_WINDOW: usize = 4;
+
+/// Finds the points in a progression where the implied key changes
+///
+/// Every run of four consecutive chords is matched against the twelve major
+/// keys and their relative minors, and the key in which the most chords are
+/// diatonic wins. Ties go to the current key, then to the key whose tonic
+/// chord appears most often, then to the major key. A new key only takes
+/// over when it fits strictly better than the current one; the change is
+/// placed at the first chord in the run that lies outside the old key.
+///
+/// The first entry is always index 0 with the opening key. An empty
+/// progression gives an empty result.
+pub fn detect_modulation(chords: &[Chord]) -> Vec<(usize, Key)> {
+    if chords.is_empty() {
+        return Vec::new();
+    }
+
+    let candidates: Vec<(Key, Scale)> = Key::circle_of_fifths()
+        .flat_map(|key| [key, key.relative()])
+        .map(|key| (key, key.scale()))
+        .collect();
+    let window = MODULATION_WINDOW.min(chords.len());
+
+    let mut changes: Vec<(usize, Key, Scale)> = Vec::new();
+    for start in 0..=chords.len() - window {
+        let run = &chords[start..start + window];
+        let current = changes.last().map(|(_, key, scale)| (*key, scale));
+
+        let (best_key, best_scale) = candidates
+            .iter()
+            .max_by_key(|(key, scale)| {
+                (
+                    diatonic_count(run, scale),
+                    current.is_some_and(|(current, _)| current == *key),
+                    run.iter()
+                        .filter(|chord| is_tonic_chord(chord, key))
+                        .count(),
+                    key.mode() == Mode::Major,
+                )
+            })
+            .expect("there is always a candidate key");
+
+        match current {
+            None => changes.push((0, *best_key, best_scale.clone())),
+            Some((key, scale))
+                if key != *best_key
+                    && diatonic_count(run, best_scale) > diatonic_count(run, scale) =>
+            {
+                let index = run
+                    .iter()
+                    .position(|chord| !is_diatonic(chord, scale))
+                    .map_or(start, |offset| start + offset);
+                changes.push((index, *best_key, best_scale.clone()));
+            }
+            Some(_) => {}
+        }
+    }
+
+    changes
+        .into_iter()
+        .map(|(index, key, _)| (index, key))
+        .collect()
+}
+
+/// Guesses the major or minor key of a progression
+///
+/// Each of the twelve major keys and their relative minors is scored by,
+/// in order:
+///
+/// 1. how many chords are diatonic to it;
+/// 2. whether the first and last chords are its tonic chord;
+/// 3. how many of the progression's pitch classes lie in its scale.
+///
+/// Remaining ties go to the key nearest C on the circle of fifths, major
+/// before minor. The best key's scale is returned: Ionian for major keys
+/// and Aeolian for minor ones. Returns `None` for an empty progression.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{Chord, NoteName, Scale, ScaleType};
+/// use chordy::analysis::infer_key_from_progression;
+///
+/// let chords = [NoteName::C, NoteName::F, NoteName::G, NoteName::C].map(Chord::major);
+/// assert_eq!(infer_key_from_progression(&chords), Some(Scale::new(NoteName::C, ScaleType::Major)));
+/// ```
+pub fn infer_key_from_progression(chords: &[Chord]) -> Option<Scale> {
+    let (first, last) = (chords.first()?, chords.last()?);
+
+    let mut pitch_classes: Vec<NoteName> = Vec::new();
+    for note in chords.iter().flat_map(|chord| chord.notes()) {
+        if !pitch_classes.iter().any(|n| n.is_enharmonic_with(&note)) {
+            pitch_classes.push(note);
+        }
+    }
+
+    Key::circle_of_fifths()
+        .flat_map(|key| [key, key.relative()])
+        .map(|key| {
+            let scale = key.scale();
+            let tonic_bias = [first, last]
+                .iter()
+                .filter(|chord| is_tonic_chord(chord, &key))
+                .count();
+            let tones = pitch_classes.iter().filter(|n| scale.contains(n)).count();
+            ((diatonic_count(chords, &scale), tonic_bias, tones), scale)
+        })
+        .reduce(|best, candidate| {
+            if candidate.0 > best.0 {
+                candidate
+            } else {
+                best
+            }
+        })
+        .map(|(_, scale)| scale)
+}
+
+/// Returns the interval from each chord's root to the next one
+///
+/// Intervals are measured upwards within an octave, so a falling fifth
+/// shows up as a rising perfect fourth. A progression of `n` chords gives
+/// `n - 1` motions.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{Chord, Interval, NoteName, SeventhType};
+/// use chordy::analysis::root_motions;
+///
+/// let ii_v_i = [
+///     Chord::seventh(NoteName::D, SeventhType::Minor),
+///     Chord::seventh(NoteName::G, SeventhType::Dominant),
+///     Chord::seventh(NoteName::C, SeventhType::Major),
+/// ];
+/// assert_eq!(root_motions(&ii_v_i), [Interval::PERFECT_FOURTH, Interval::PERFECT_FOURTH]);
+/// ```
+pub fn root_motions(chords: &[Chord]) -> Vec<Interval> {
+    chords
+        .windows(2)
+        .map(|pair| pair[0].root().interval_to(&pair[1].root()))
+        .collect()
+}
+
+/// Checks whether two chords are chromatic mediants
+///
+/// Chromatic mediants have roots a major or minor third apart, share the
+/// same quality (both major or both minor), and have exactly one common
+/// tone, as in C major and A♭ major or C major and E major. Diatonic
+/// mediants such as C major and A minor share two tones and don't count.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{note, Chord, NoteName};
+/// use chordy::analysis::is_chromatic_mediant;
+///
+/// assert!(is_chromatic_mediant(&Chord::major(NoteName::C), &Chord::major(note!("Ab"))));
+/// assert!(!is_chromatic_mediant(&Chord::major(NoteName::C), &Chord::minor(NoteName::A)));
+/// ```
+pub fn is_chromatic_mediant(a: &Chord, b: &Chord) -> bool {
+    let roots = a.root().interval_to(&b.root()).semitones().rem_euclid(12);
+    let same_quality = matches!(
+        (a.quality(), b.quality()),
+        (ChordQuality::Major, ChordQuality::Major) | (ChordQuality::Minor, ChordQuality::Minor)
+    );
+    matches!(roots, 3 | 4 | 8 | 9) && same_quality && a.common_tones(b).len() == 1
+}
+
+fn is_diatonic(chord: &Chord, scale: &Scale) -> bool {
+    chord.notes().iter().all(|note| scale.contains(note))
+}
+
+fn diatonic_count(chords: &[Chord], scale: &Scale) -> usize {
+    chords
+        .iter()
+        .filter(|chord| is_diatonic(chord, scale))
+        .count()
+}
+
+fn is_tonic_chord(chord: &Chord, key: &Key) -> bool {
+    let quality = match key.mode() {
+        Mode::Major => ChordQuality::Major,
+        Mode::Minor => ChordQuality::Minor,
+    };
+    chord.root().is_enharmonic_with(&key.tonic()) && chord.quality() == quality
+}
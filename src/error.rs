@@ -13,6 +13,12 @@ pub enum ParseError {
     /// Error when an invalid scale type is provided
     InvalidScaleType(String),
 
+    /// Error when an invalid interval name is provided
+    InvalidInterval(String),
+
+    /// Error when an invalid roman numeral is provided
+    InvalidRomanNumeral(String),
+
     /// Error when a string doesn't match any known pattern
     UnrecognizedFormat(String),
 }
@@ -24,6 +30,8 @@ impl std::fmt::Display for ParseError {
             ParseError::InvalidNoteName(s) => write!(f, "Invalid note name: '{}'", s),
             ParseError::InvalidChordSymbol(s) => write!(f, "Invalid chord symbol: '{}'", s),
             ParseError::InvalidScaleType(s) => write!(f, "Invalid scale type: '{}'", s),
+            ParseError::InvalidInterval(s) => write!(f, "Invalid interval: '{}'", s),
+            ParseError::InvalidRomanNumeral(s) => write!(f, "Invalid roman numeral: '{}'", s),
             ParseError::UnrecognizedFormat(s) => write!(f, "Unrecognized format: '{}'", s),
         }
     }
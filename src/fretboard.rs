@@ -0,0 +1,138 @@
+use crate::types::{Chord, NoteName};
+
+/// Standard guitar tuning, from the low E string to the high E string
+pub const STANDARD_TUNING: [NoteName; 6] = [
+    NoteName::E,
+    NoteName::A,
+    NoteName::D,
+    NoteName::G,
+    NoteName::B,
+    NoteName::E,
+];
+
+/// Most frets a hand can cover between the lowest and highest fretted note
+const MAX_SPAN: u8 = 3;
+
+/// Fingers available for fretting; open strings don't need one
+const FINGERS: usize = 4;
+
+/// Finds playable fingerings of a chord on a fretted instrument
+///
+/// `tuning` lists the open strings from lowest to highest, such as
+/// [`STANDARD_TUNING`]. Each shape has one entry per string: the fret to
+/// play, with 0 for an open string, or `None` for a muted one. A shape is
+/// kept when it:
+///
+/// - sounds every pitch class of the chord and nothing else;
+/// - has the chord's root as its lowest sounding note;
+/// - mutes only strings below the bass note;
+/// - keeps fretted notes within four frets of each other;
+/// - needs at most four fingers, with the lowest fretted notes barred by one.
+///
+/// Shapes are ordered by their highest fret, so open shapes come first,
+/// then by the number of sounding strings, most first.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{Chord, NoteName};
+/// use chordy::fretboard::{chord_shapes, STANDARD_TUNING};
+///
+/// let shapes = chord_shapes(&Chord::minor(NoteName::E), &STANDARD_TUNING, 5);
+/// assert_eq!(shapes[0], [Some(0), Some(2), Some(2), Some(0), Some(0), Some(0)]);
+/// ```
+pub fn chord_shapes(chord: &Chord, tuning: &[NoteName], max_fret: u8) -> Vec<Vec<Option<u8>>> {
+    let pitch_classes: Vec<u8> = chord.notes().iter().map(|n| n.pitch_class()).collect();
+    let root = chord.root().pitch_class();
+
+    let mut shapes = Vec::new();
+    let mut shape = Vec::with_capacity(tuning.len());
+    search(
+        &mut shape,
+        tuning,
+        max_fret,
+        &pitch_classes,
+        root,
+        &mut shapes,
+    );
+
+    shapes.sort_by_key(|shape: &Vec<Option<u8>>| {
+        let highest = shape.iter().flatten().max().copied().unwrap_or(0);
+        let sounding = shape.iter().flatten().count();
+        (highest, std::cmp::Reverse(sounding))
+    });
+    shapes
+}
+
+/// Extends `shape` one string at a time, collecting every complete playable shape
+fn search(
+    shape: &mut Vec<Option<u8>>,
+    tuning: &[NoteName],
+    max_fret: u8,
+    pitch_classes: &[u8],
+    root: u8,
+    shapes: &mut Vec<Vec<Option<u8>>>,
+) {
+    let string = shape.len();
+    if string == tuning.len() {
+        if is_complete(shape, tuning, pitch_classes) {
+            shapes.push(shape.clone());
+        }
+        return;
+    }
+
+    let open = tuning[string].pitch_class();
+    let bass_placed = shape.iter().any(Option::is_some);
+
+    // Strings below the bass may be muted; above it, every string sounds
+    if !bass_placed {
+        shape.push(None);
+        search(shape, tuning, max_fret, pitch_classes, root, shapes);
+        shape.pop();
+    }
+
+    for fret in 0..=max_fret {
+        let pitch_class = fretted_pitch_class(open, fret);
+        let fits = if bass_placed {
+            pitch_classes.contains(&pitch_class)
+        } else {
+            pitch_class == root
+        };
+        if !fits {
+            continue;
+        }
+
+        shape.push(Some(fret));
+        if is_reachable(shape) {
+            search(shape, tuning, max_fret, pitch_classes, root, shapes);
+        }
+        shape.pop();
+    }
+}
+
+/// Checks the span and finger count of the fretted notes so far
+fn is_reachable(shape: &[Option<u8>]) -> bool {
+    let fretted: Vec<u8> = shape.iter().flatten().copied().filter(|&f| f > 0).collect();
+    let (Some(&lowest), Some(&highest)) = (fretted.iter().min(), fretted.iter().max()) else {
+        return true;
+    };
+
+    let barre = usize::from(fretted.contains(&lowest));
+    let fingers = barre + fretted.iter().filter(|&&f| f > lowest).count();
+    highest - lowest <= MAX_SPAN && fingers <= FINGERS
+}
+
+/// Checks that a full shape sounds every chord tone
+fn is_complete(shape: &[Option<u8>], tuning: &[NoteName], pitch_classes: &[u8]) -> bool {
+    let sounded: Vec<u8> = shape
+        .iter()
+        .zip(tuning)
+        .filter_map(|(fret, open)| fret.map(|f| fretted_pitch_class(open.pitch_class(), f)))
+        .collect();
+    pitch_classes.iter().all(|pc| sounded.contains(pc))
+}
+
+/// Returns the pitch class sounded at `fret`, summing in u16 so high frets can't overflow
+fn fretted_pitch_class(open: u8, fret: u8) -> u8 {
+    ((u16::from(open) + u16::from(fret)) % 12) as u8
+}
@@ -1,4 +1,11 @@
+pub mod analysis;
 pub mod error;
+pub mod fretboard;
+pub mod macros;
+pub mod scales;
 pub mod symbols;
+pub mod transposition;
+pub mod tuning;
 pub mod types;
+pub mod voicing;
 pub use types::*;
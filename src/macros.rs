@@ -0,0 +1,165 @@
+use crate::types::{Accidental, Letter, NoteName, Pitch};
+
+/// Creates a [`NoteName`] from a string literal, checked at compile time
+///
+/// Accepts the same spellings as `NoteName::from_str`: a capital letter
+/// followed by an optional accidental (`b`, `#`, `bb`, `##`, `n`, or the
+/// Unicode symbols `♭`, `♯`, `𝄫`, `𝄪`, `♮`). The natural sign is optional,
+/// so `note!("Cn")` and `note!("C")` are the same note.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{note, NoteName, Letter, Accidental};
+///
+/// assert_eq!(note!("F#"), NoteName::new(Letter::F, Accidental::Sharp));
+/// assert_eq!(note!("B♭"), NoteName::new(Letter::B, Accidental::Flat));
+/// assert_eq!(note!("Cn"), note!("C"));
+/// ```
+///
+/// Anything else, including surrounding whitespace, fails to compile:
+///
+/// ```compile_fail
+/// let note = chordy::note!("C ");
+/// ```
+#[macro_export]
+macro_rules! note {
+    ($name:literal) => {{
+        const NOTE: $crate::NoteName = match $crate::macros::parse_note($name) {
+            Some(note) => note,
+            None => panic!(concat!("invalid note name: ", stringify!($name))),
+        };
+        NOTE
+    }};
+}
+
+/// Creates a [`Pitch`] from a string literal, checked at compile time
+///
+/// The literal is a note name as accepted by [`note!`] followed by an
+/// octave number, which may be negative.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{note, pitch, Pitch};
+///
+/// assert_eq!(pitch!("C#4"), Pitch::new(note!("C#"), 4));
+/// assert_eq!(pitch!("B♭-1"), Pitch::new(note!("Bb"), -1));
+/// ```
+///
+/// A missing octave fails to compile:
+///
+/// ```compile_fail
+/// let pitch = chordy::pitch!("C");
+/// ```
+#[macro_export]
+macro_rules! pitch {
+    ($name:literal) => {{
+        const PITCH: $crate::Pitch = match $crate::macros::parse_pitch($name) {
+            Some(pitch) => pitch,
+            None => panic!(concat!("invalid pitch: ", stringify!($name))),
+        };
+        PITCH
+    }};
+}
+
+/// Checks whether a string is a note name accepted by [`note!`]
+#[doc(hidden)]
+pub const fn is_valid_note(s: &str) -> bool {
+    parse_note(s).is_some()
+}
+
+/// Parses a note name in a const context; used by [`note!`] and `NoteName::from_str`
+#[doc(hidden)]
+pub const fn parse_note(s: &str) -> Option<NoteName> {
+    parse_note_bytes(s.as_bytes())
+}
+
+/// Parses a pitch such as `C#4` or `B♭-1` in a const context; used by [`pitch!`]
+#[doc(hidden)]
+pub const fn parse_pitch(s: &str) -> Option<Pitch> {
+    let bytes = s.as_bytes();
+
+    // The octave is the trailing run of digits, with an optional minus sign
+    let mut split = bytes.len();
+    while split > 0 && bytes[split - 1].is_ascii_digit() {
+        split -= 1;
+    }
+    if split == bytes.len() {
+        return None;
+    }
+    let negative = split > 0 && bytes[split - 1] == b'-';
+    let (name, digits) = bytes.split_at(split);
+    let name = if negative {
+        name.split_at(name.len() - 1).0
+    } else {
+        name
+    };
+
+    let mut octave: i16 = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        octave = octave * 10 + (digits[i] - b'0') as i16;
+        if octave > i8::MAX as i16 + 1 {
+            return None;
+        }
+        i += 1;
+    }
+    let octave = if negative { -octave } else { octave };
+    if octave > i8::MAX as i16 {
+        return None;
+    }
+
+    match parse_note_bytes(name) {
+        Some(note) => Some(Pitch::new(note, octave as i8)),
+        None => None,
+    }
+}
+
+const fn parse_note_bytes(bytes: &[u8]) -> Option<NoteName> {
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let letter = match bytes[0] {
+        b'C' => Letter::C,
+        b'D' => Letter::D,
+        b'E' => Letter::E,
+        b'F' => Letter::F,
+        b'G' => Letter::G,
+        b'A' => Letter::A,
+        b'B' => Letter::B,
+        _ => return None,
+    };
+
+    let rest = bytes.split_at(1).1;
+    let accidental = if rest.is_empty() || eq(rest, b"n") || eq(rest, "♮".as_bytes()) {
+        Accidental::Natural
+    } else if eq(rest, b"b") || eq(rest, "♭".as_bytes()) {
+        Accidental::Flat
+    } else if eq(rest, b"#") || eq(rest, "♯".as_bytes()) {
+        Accidental::Sharp
+    } else if eq(rest, b"bb") || eq(rest, "𝄫".as_bytes()) {
+        Accidental::DoubleFlat
+    } else if eq(rest, b"##") || eq(rest, "𝄪".as_bytes()) {
+        Accidental::DoubleSharp
+    } else {
+        return None;
+    };
+
+    Some(NoteName::new(letter, accidental))
+}
+
+const fn eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
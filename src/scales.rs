@@ -0,0 +1,652 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+use crate::types::{Interval, ScaleType};
+
+/// The shape of a scale: a name and the interval of each degree above the tonic
+///
+/// Definitions don't have a tonic; combine one with a tonic in
+/// [`Scale::new`](crate::Scale::new) to get actual notes. The built-in
+/// definitions are constants; [`ScaleDefinition::custom`] builds one at
+/// runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaleDefinition {
+    name: Cow<'static, str>,
+    intervals: Cow<'static, [Interval]>,
+    bitmask: ScaleBitmask,
+}
+
+impl ScaleDefinition {
+    pub const fn new(name: &'static str, intervals: &'static [Interval]) -> Self {
+        ScaleDefinition {
+            name: Cow::Borrowed(name),
+            intervals: Cow::Borrowed(intervals),
+            bitmask: ScaleBitmask::from_intervals(intervals),
+        }
+    }
+
+    /// Creates a definition with an owned name and intervals
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::Interval;
+    /// use chordy::scales::ScaleDefinition;
+    ///
+    /// let intervals = vec![Interval::PERFECT_UNISON, Interval::MAJOR_THIRD, Interval::PERFECT_FIFTH];
+    /// let triad = ScaleDefinition::custom("Triad", intervals);
+    /// assert_eq!(triad.name(), "Triad");
+    /// assert_eq!(triad.intervals().len(), 3);
+    /// ```
+    pub fn custom(name: impl Into<String>, intervals: Vec<Interval>) -> Self {
+        ScaleDefinition {
+            name: Cow::Owned(name.into()),
+            bitmask: ScaleBitmask::from_intervals(&intervals),
+            intervals: Cow::Owned(intervals),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the interval of each scale degree above the tonic, starting with the unison
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Returns the pitch classes of the scale relative to its tonic
+    pub fn bitmask(&self) -> ScaleBitmask {
+        self.bitmask
+    }
+}
+
+/// A set of pitch classes relative to a tonic, one bit per semitone
+///
+/// Bit 0 is the tonic, bit 1 the minor second, up to bit 11 for the major
+/// seventh. Spelling is lost, so an augmented fourth and a diminished fifth
+/// set the same bit.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::Interval;
+/// use chordy::scales::ScaleBitmask;
+///
+/// let mask = ScaleBitmask::from_intervals(&[Interval::PERFECT_UNISON, Interval::MAJOR_THIRD]);
+/// assert_eq!(mask.bits(), 0b0000_0001_0001);
+/// assert!(mask.contains(4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScaleBitmask(u16);
+
+impl ScaleBitmask {
+    /// Creates a bitmask from raw bits; anything above bit 11 is dropped
+    pub const fn new(bits: u16) -> Self {
+        ScaleBitmask(bits & 0x0FFF)
+    }
+
+    /// Creates a bitmask with a bit set for each interval, reduced to within an octave
+    pub const fn from_intervals(intervals: &[Interval]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < intervals.len() {
+            bits |= 1 << intervals[i].semitones().rem_euclid(12);
+            i += 1;
+        }
+        ScaleBitmask(bits)
+    }
+
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Checks whether the pitch class a number of semitones above the tonic is set
+    pub fn contains(&self, semitones: u8) -> bool {
+        self.0 & (1 << (semitones % 12)) != 0
+    }
+
+    /// Returns the number of pitch classes set
+    pub fn note_count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Checks whether every pitch class set here is also set in `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{Chord, HasIntervals, NoteName};
+    /// use chordy::scales::{self, ScaleBitmask};
+    ///
+    /// let triad = ScaleBitmask::from_intervals(Chord::major(NoteName::C).intervals());
+    /// assert!(triad.is_subset_of(&scales::IONIAN.bitmask()));
+    /// assert!(!triad.is_subset_of(&scales::AEOLIAN.bitmask()));
+    /// ```
+    pub fn is_subset_of(&self, other: &ScaleBitmask) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Re-roots the bitmask on the pitch class `semitones` above the tonic
+    ///
+    /// Rotating a scale's bitmask to one of its own degrees gives the mode
+    /// on that degree, so rotating Ionian by 2 gives Dorian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::scales;
+    ///
+    /// assert_eq!(scales::IONIAN.bitmask().rotate(2), scales::DORIAN.bitmask());
+    /// ```
+    pub fn rotate(&self, semitones: u8) -> ScaleBitmask {
+        let shift = semitones % 12;
+        ScaleBitmask::new((self.0 >> shift) | (self.0 << (12 - shift)))
+    }
+
+    /// Iterates over the twelve rotations, starting with the bitmask itself
+    pub fn rotations(&self) -> impl Iterator<Item = ScaleBitmask> {
+        let mask = *self;
+        (0..12).map(move |semitones| mask.rotate(semitones))
+    }
+}
+
+impl From<ScaleType> for ScaleDefinition {
+    fn from(scale_type: ScaleType) -> Self {
+        match scale_type {
+            ScaleType::Major => IONIAN,
+            ScaleType::NaturalMinor => AEOLIAN,
+            ScaleType::HarmonicMinor => HARMONIC_MINOR,
+            ScaleType::MelodicMinor => MELODIC_MINOR,
+            ScaleType::Dorian => DORIAN,
+            ScaleType::Phrygian => PHRYGIAN,
+            ScaleType::Lydian => LYDIAN,
+            ScaleType::Mixolydian => MIXOLYDIAN,
+            ScaleType::Locrian => LOCRIAN,
+        }
+    }
+}
+
+impl FromStr for ScaleDefinition {
+    type Err = ParseError;
+
+    /// Parses a built-in scale name, with the same rules as [`by_name`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        by_name(s).ok_or_else(|| ParseError::InvalidScaleType(s.to_string()))
+    }
+}
+
+pub const IONIAN: ScaleDefinition = ScaleDefinition::new(
+    "Ionian",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MAJOR_SEVENTH,
+    ],
+);
+
+pub const DORIAN: ScaleDefinition = ScaleDefinition::new(
+    "Dorian",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const PHRYGIAN: ScaleDefinition = ScaleDefinition::new(
+    "Phrygian",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const LYDIAN: ScaleDefinition = ScaleDefinition::new(
+    "Lydian",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::AUGMENTED_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MAJOR_SEVENTH,
+    ],
+);
+
+pub const MIXOLYDIAN: ScaleDefinition = ScaleDefinition::new(
+    "Mixolydian",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const AEOLIAN: ScaleDefinition = ScaleDefinition::new(
+    "Aeolian",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const LOCRIAN: ScaleDefinition = ScaleDefinition::new(
+    "Locrian",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::DIMINISHED_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const HARMONIC_MINOR: ScaleDefinition = ScaleDefinition::new(
+    "Harmonic Minor",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::MAJOR_SEVENTH,
+    ],
+);
+
+pub const MELODIC_MINOR: ScaleDefinition = ScaleDefinition::new(
+    "Melodic Minor",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MAJOR_SEVENTH,
+    ],
+);
+
+/// The seventh mode of melodic minor, spelled the way it is used over an
+/// altered dominant (♭9, ♯9, ♯11, ♭13)
+pub const ALTERED: ScaleDefinition = ScaleDefinition::new(
+    "Altered",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_SECOND,
+        Interval::AUGMENTED_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::AUGMENTED_FOURTH,
+        Interval::MINOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+// Modes of the harmonic minor scale, from the second degree up
+
+pub const LOCRIAN_NATURAL_6: ScaleDefinition = ScaleDefinition::new(
+    "Locrian Natural 6",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::DIMINISHED_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const IONIAN_AUGMENTED: ScaleDefinition = ScaleDefinition::new(
+    "Ionian Augmented",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::AUGMENTED_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MAJOR_SEVENTH,
+    ],
+);
+
+pub const DORIAN_SHARP_4: ScaleDefinition = ScaleDefinition::new(
+    "Dorian Sharp 4",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::AUGMENTED_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const PHRYGIAN_DOMINANT: ScaleDefinition = ScaleDefinition::new(
+    "Phrygian Dominant",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const LYDIAN_SHARP_2: ScaleDefinition = ScaleDefinition::new(
+    "Lydian Sharp 2",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::AUGMENTED_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::AUGMENTED_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MAJOR_SEVENTH,
+    ],
+);
+
+pub const ULTRALOCRIAN: ScaleDefinition = ScaleDefinition::new(
+    "Ultralocrian",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::DIMINISHED_FOURTH,
+        Interval::DIMINISHED_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::DIMINISHED_SEVENTH,
+    ],
+);
+
+// Modes of the melodic minor scale, from the second degree up (the seventh
+// mode is the altered scale)
+
+pub const DORIAN_FLAT_2: ScaleDefinition = ScaleDefinition::new(
+    "Dorian Flat 2",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const LYDIAN_AUGMENTED: ScaleDefinition = ScaleDefinition::new(
+    "Lydian Augmented",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::AUGMENTED_FOURTH,
+        Interval::AUGMENTED_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MAJOR_SEVENTH,
+    ],
+);
+
+pub const LYDIAN_DOMINANT: ScaleDefinition = ScaleDefinition::new(
+    "Lydian Dominant",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::AUGMENTED_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const MIXOLYDIAN_FLAT_6: ScaleDefinition = ScaleDefinition::new(
+    "Mixolydian Flat 6",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const LOCRIAN_NATURAL_2: ScaleDefinition = ScaleDefinition::new(
+    "Locrian Natural 2",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::DIMINISHED_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+/// Six whole tones to the octave
+///
+/// Spelled with sharps up to the augmented fifth and a minor seventh on
+/// top, so C whole tone is C D E F♯ G♯ B♭: six different letters, and the
+/// top note reads as the familiar ♭7 rather than A♯.
+pub const WHOLE_TONE: ScaleDefinition = ScaleDefinition::new(
+    "Whole Tone",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::AUGMENTED_FOURTH,
+        Interval::AUGMENTED_FIFTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+/// Alternating minor thirds and semitones, C E♭ E G A♭ B
+///
+/// Also called the hexatonic or symmetric augmented scale: two augmented
+/// triads a minor third apart.
+pub const AUGMENTED: ScaleDefinition = ScaleDefinition::new(
+    "Augmented",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_THIRD,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SIXTH,
+        Interval::MAJOR_SEVENTH,
+    ],
+);
+
+/// The mode of the augmented scale starting on its second degree, C D♭ E F G♯ A
+pub const AUGMENTED_INVERSE: ScaleDefinition = ScaleDefinition::new(
+    "Augmented Inverse",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::AUGMENTED_FIFTH,
+        Interval::MAJOR_SIXTH,
+    ],
+);
+
+// The pentatonic scale and its modes, from the major pentatonic up
+
+pub const MAJOR_PENTATONIC: ScaleDefinition = ScaleDefinition::new(
+    "Major Pentatonic",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::MAJOR_THIRD,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+    ],
+);
+
+pub const SUSPENDED_PENTATONIC: ScaleDefinition = ScaleDefinition::new(
+    "Suspended Pentatonic",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const BLUES_MINOR_PENTATONIC: ScaleDefinition = ScaleDefinition::new(
+    "Blues Minor Pentatonic",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::MINOR_SIXTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+pub const BLUES_MAJOR_PENTATONIC: ScaleDefinition = ScaleDefinition::new(
+    "Blues Major Pentatonic",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MAJOR_SIXTH,
+    ],
+);
+
+pub const MINOR_PENTATONIC: ScaleDefinition = ScaleDefinition::new(
+    "Minor Pentatonic",
+    &[
+        Interval::PERFECT_UNISON,
+        Interval::MINOR_THIRD,
+        Interval::PERFECT_FOURTH,
+        Interval::PERFECT_FIFTH,
+        Interval::MINOR_SEVENTH,
+    ],
+);
+
+/// All built-in scale definitions
+///
+/// The registry is part of the public behaviour: [`matching`], [`by_name`]
+/// and [`Chord::matching_scales`](crate::Chord::matching_scales) search it,
+/// and scale modes are looked up in it. Adding a definition can therefore
+/// add results to those functions. Results keep the registry's order, with
+/// the diatonic modes first, then the modes of the melodic and harmonic
+/// minor, then symmetric and pentatonic scales.
+pub const REGISTRY: &[ScaleDefinition] = &[
+    IONIAN,
+    DORIAN,
+    PHRYGIAN,
+    LYDIAN,
+    MIXOLYDIAN,
+    AEOLIAN,
+    LOCRIAN,
+    HARMONIC_MINOR,
+    MELODIC_MINOR,
+    ALTERED,
+    LOCRIAN_NATURAL_6,
+    IONIAN_AUGMENTED,
+    DORIAN_SHARP_4,
+    PHRYGIAN_DOMINANT,
+    LYDIAN_SHARP_2,
+    ULTRALOCRIAN,
+    DORIAN_FLAT_2,
+    LYDIAN_AUGMENTED,
+    LYDIAN_DOMINANT,
+    MIXOLYDIAN_FLAT_6,
+    LOCRIAN_NATURAL_2,
+    WHOLE_TONE,
+    AUGMENTED,
+    AUGMENTED_INVERSE,
+    MAJOR_PENTATONIC,
+    SUSPENDED_PENTATONIC,
+    BLUES_MINOR_PENTATONIC,
+    BLUES_MAJOR_PENTATONIC,
+    MINOR_PENTATONIC,
+];
+
+/// The seven modes of the major scale, from Ionian to Locrian
+pub const DIATONIC_MODES: &[ScaleDefinition] = &[
+    IONIAN, DORIAN, PHRYGIAN, LYDIAN, MIXOLYDIAN, AEOLIAN, LOCRIAN,
+];
+
+/// Returns every built-in scale definition with exactly the given pitch classes
+///
+/// # Examples
+///
+/// ```
+/// use chordy::scales;
+///
+/// let found = scales::matching(scales::LYDIAN.bitmask());
+/// assert_eq!(found, vec![scales::LYDIAN]);
+/// ```
+pub fn matching(bitmask: ScaleBitmask) -> Vec<ScaleDefinition> {
+    REGISTRY
+        .iter()
+        .filter(|definition| definition.bitmask == bitmask)
+        .cloned()
+        .collect()
+}
+
+/// Looks up a built-in scale definition by name, ignoring case
+///
+/// Besides the registry names, `"major"` resolves to Ionian and `"minor"` to
+/// Aeolian.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::scales;
+///
+/// assert_eq!(scales::by_name("dorian"), Some(scales::DORIAN));
+/// assert_eq!(scales::by_name("Minor"), Some(scales::AEOLIAN));
+/// assert_eq!(scales::by_name("bebop"), None);
+/// ```
+pub fn by_name(name: &str) -> Option<ScaleDefinition> {
+    if name.eq_ignore_ascii_case("major") {
+        return Some(IONIAN);
+    }
+    if name.eq_ignore_ascii_case("minor") {
+        return Some(AEOLIAN);
+    }
+
+    REGISTRY
+        .iter()
+        .find(|definition| definition.name.eq_ignore_ascii_case(name))
+        .cloned()
+}
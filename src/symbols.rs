@@ -1,27 +1,47 @@
-#[cfg(feature = "utf8_symbols")]
-pub const FLAT: &str = "♭";
-#[cfg(not(feature = "utf8_symbols"))]
-pub const FLAT: &str = "b";
+/// Plain ASCII accidentals, for output targets without Unicode support
+///
+/// ASCII has no natural sign, so [`ascii::NATURAL`] is the letter `n`, as in
+/// Humdrum's kern format. Note names never include it: a natural note is
+/// written as the bare letter.
+pub mod ascii {
+    pub const FLAT: &str = "b";
+    pub const SHARP: &str = "#";
+    pub const DOUBLE_FLAT: &str = "bb";
+    pub const DOUBLE_SHARP: &str = "##";
+    /// An explicit natural, written as the letter `n`
+    pub const NATURAL: &str = "n";
+}
+
+/// Unicode musical symbols for accidentals
+pub mod unicode {
+    pub const FLAT: &str = "♭";
+    pub const SHARP: &str = "♯";
+    pub const DOUBLE_FLAT: &str = "𝄫";
+    pub const DOUBLE_SHARP: &str = "𝄪";
+    pub const NATURAL: &str = "♮";
+}
 
 #[cfg(feature = "utf8_symbols")]
-pub const SHARP: &str = "♯";
+pub const FLAT: &str = unicode::FLAT;
 #[cfg(not(feature = "utf8_symbols"))]
-pub const SHARP: &str = "#";
+pub const FLAT: &str = ascii::FLAT;
 
 #[cfg(feature = "utf8_symbols")]
-pub const DOUBLE_FLAT: &str = "𝄫";
+pub const SHARP: &str = unicode::SHARP;
 #[cfg(not(feature = "utf8_symbols"))]
-pub const DOUBLE_FLAT: &str = "bb";
+pub const SHARP: &str = ascii::SHARP;
 
 #[cfg(feature = "utf8_symbols")]
-pub const DOUBLE_SHARP: &str = "𝄪";
+pub const DOUBLE_FLAT: &str = unicode::DOUBLE_FLAT;
 #[cfg(not(feature = "utf8_symbols"))]
-pub const DOUBLE_SHARP: &str = "##";
+pub const DOUBLE_FLAT: &str = ascii::DOUBLE_FLAT;
 
 #[cfg(feature = "utf8_symbols")]
-pub const NATURAL: &str = "♮";
+pub const DOUBLE_SHARP: &str = unicode::DOUBLE_SHARP;
 #[cfg(not(feature = "utf8_symbols"))]
-pub const NATURAL: &str = "♮"; // Still use it even without utf8 feature
+pub const DOUBLE_SHARP: &str = ascii::DOUBLE_SHARP;
+
+pub const NATURAL: &str = unicode::NATURAL; // Still use it even without utf8 feature
 
 // Note names
 pub const C: &str = "C";
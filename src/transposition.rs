@@ -0,0 +1,265 @@
+use crate::types::{Accidental, Interval, Key, Letter, NoteName, Pitch};
+
+/// Moves pitches by a number of semitones, choosing a spelling for the result
+pub trait Transposer {
+    fn transpose(&self, pitch: Pitch, semitones: i8) -> Pitch;
+}
+
+/// Transposes by semitones and picks the simplest spelling for each result
+///
+/// Every letter/accidental combination that lands on the target pitch is
+/// ranked by these criteria, in order:
+///
+/// 1. fewer accidentals: naturals beat single accidentals, which beat doubles;
+/// 2. accidentals that match the source note's accidental (or, for natural
+///    notes, the direction of motion) beat ones that go against it;
+/// 3. sharps beat flats.
+///
+/// No two spellings of a pitch class agree on all three, so the result never
+/// depends on iteration order. Rising chromatically from C gives C♯, falling
+/// from D gives D♭, and a tritone up from C gives F♯ while a tritone down
+/// gives G♭.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Pitch};
+/// use chordy::transposition::{ChromaticTransposer, Transposer};
+///
+/// let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+/// let up = ChromaticTransposer.transpose(c4, 1);
+/// assert_eq!(up, Pitch::new(NoteName::new(Letter::C, Accidental::Sharp), 4));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChromaticTransposer;
+
+impl ChromaticTransposer {
+    /// Ranks a candidate spelling; lower is better
+    fn penalty(candidate: NoteName, direction: i8) -> (u8, bool, bool) {
+        let offset = candidate.accidental().semitone_offset();
+        (
+            offset.unsigned_abs(),
+            offset != 0 && offset.signum() != direction,
+            offset < 0,
+        )
+    }
+
+    /// Spells the transposed pitch, favouring accidentals that point in
+    /// `direction` (positive for sharps, negative for flats)
+    fn spell(pitch: Pitch, semitones: i8, direction: i8) -> Pitch {
+        let target = pitch.midi_number() + semitones as i16;
+
+        let name = Letter::all()
+            .into_iter()
+            .flat_map(|letter| {
+                Accidental::all()
+                    .into_iter()
+                    .map(move |accidental| NoteName::new(letter, accidental))
+            })
+            .filter(|candidate| (candidate.base_midi_number() as i16 - target).rem_euclid(12) == 0)
+            .min_by_key(|&candidate| Self::penalty(candidate, direction))
+            .expect("every pitch class has a spelling");
+        Pitch::from_midi_number(name, target)
+    }
+}
+
+impl Transposer for ChromaticTransposer {
+    fn transpose(&self, pitch: Pitch, semitones: i8) -> Pitch {
+        let direction = match pitch.name().accidental().semitone_offset().signum() {
+            0 => semitones.signum(),
+            sign => sign,
+        };
+        Self::spell(pitch, semitones, direction)
+    }
+}
+
+/// Transposes by semitones, spelling black keys with a preferred accidental
+///
+/// Uses the same scoring as [`ChromaticTransposer`], but accidentals are
+/// always biased towards `prefer` instead of following the source note. A
+/// natural preference falls back to the direction of motion.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Pitch};
+/// use chordy::transposition::{SpelledTransposer, Transposer};
+///
+/// let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+/// let flats = SpelledTransposer { prefer: Accidental::Flat };
+/// assert_eq!(
+///     flats.transpose(c4, 1),
+///     Pitch::new(NoteName::new(Letter::D, Accidental::Flat), 4)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SpelledTransposer {
+    pub prefer: Accidental,
+}
+
+impl Transposer for SpelledTransposer {
+    fn transpose(&self, pitch: Pitch, semitones: i8) -> Pitch {
+        let direction = match self.prefer.semitone_offset().signum() {
+            0 => semitones.signum(),
+            sign => sign,
+        };
+        ChromaticTransposer::spell(pitch, semitones, direction)
+    }
+}
+
+/// Transposes by intervals, spelling the result strictly from the interval
+///
+/// The letter always moves by the interval's generic number, so an augmented
+/// fourth above C is F♯ and a diminished fifth above C is G♭, even though both
+/// are six semitones. When transposing by a bare number of semitones, the
+/// interval is taken to be the one closest to a perfect interval on the circle
+/// of fifths: minor seconds, thirds, sixths and sevenths, major seconds,
+/// thirds, sixths and sevenths, perfect fourths and fifths, and the augmented
+/// fourth for the tritone.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Pitch, Interval};
+/// use chordy::transposition::EnharmonicTransposer;
+///
+/// let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+/// let g_flat4 = Pitch::new(NoteName::new(Letter::G, Accidental::Flat), 4);
+/// assert_eq!(
+///     EnharmonicTransposer.transpose_by_interval(c4, Interval::DIMINISHED_FIFTH),
+///     g_flat4
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnharmonicTransposer;
+
+impl EnharmonicTransposer {
+    /// Moves the pitch by a spelled interval
+    pub fn transpose_by_interval(&self, pitch: Pitch, interval: Interval) -> Pitch {
+        pitch.transpose_by_interval(interval)
+    }
+
+    /// The interval used for a bare semitone count
+    fn interval_for(semitones: i8) -> Interval {
+        let mut fifths = (semitones as i16 * 7).rem_euclid(12);
+        if fifths > 6 {
+            fifths -= 12;
+        }
+        let octaves = (semitones as i16 - fifths * 7) / 12;
+        Interval::new(fifths as i8, octaves as i8)
+    }
+}
+
+impl Transposer for EnharmonicTransposer {
+    fn transpose(&self, pitch: Pitch, semitones: i8) -> Pitch {
+        pitch.transpose_by_interval(Self::interval_for(semitones))
+    }
+}
+
+/// Transposes a set of pitches, spelling them all with the same kind of accidental
+///
+/// Transposing a melody note by note can mix sharps and flats. This spells the
+/// whole set once with sharps and once with flats, and keeps the version where
+/// fewer notes share a letter (E♭ next to D reads better than D♯ next to D),
+/// then the one with fewer accidentals. Remaining ties follow the direction of
+/// motion.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Pitch};
+/// use chordy::transposition::transpose_all;
+///
+/// let c = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+/// let b = Pitch::new(NoteName::new(Letter::B, Accidental::Natural), 4);
+/// let up = transpose_all(&[c, b], 3);
+/// assert_eq!(up[0].name(), NoteName::new(Letter::E, Accidental::Flat));
+/// assert_eq!(up[1].name(), NoteName::new(Letter::D, Accidental::Natural));
+/// ```
+pub fn transpose_all(pitches: &[Pitch], semitones: i8) -> Vec<Pitch> {
+    let motion = if semitones < 0 { -1 } else { 1 };
+
+    [motion, -motion]
+        .into_iter()
+        .map(|direction| {
+            pitches
+                .iter()
+                .map(|&pitch| ChromaticTransposer::spell(pitch, semitones, direction))
+                .collect::<Vec<_>>()
+        })
+        .min_by_key(|spelled| (letter_clashes(spelled), accidental_count(spelled)))
+        .unwrap_or_default()
+}
+
+/// Respells a melody to fit a key, keeping every pitch where it is
+///
+/// Notes in the key's scale take the scale's spelling. Other notes are
+/// chromatic and are spelled like [`ChromaticTransposer`] would: as a natural
+/// if possible, otherwise with a sharp when the line is rising and a flat
+/// when it is falling. The direction is taken from the motion to the next
+/// note, or from the previous note at the end of the line or on a repeated
+/// note, and falls back to the key signature for a melody that never moves.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{pitch, Key, NoteName};
+/// use chordy::transposition::respell_melody;
+///
+/// let key = Key::major(NoteName::C);
+/// let up = respell_melody(&[pitch!("C4"), pitch!("Db4"), pitch!("D4")], &key);
+/// assert_eq!(up[1], pitch!("C#4"));
+/// let down = respell_melody(&[pitch!("D4"), pitch!("C#4"), pitch!("C4")], &key);
+/// assert_eq!(down[1], pitch!("Db4"));
+/// ```
+pub fn respell_melody(pitches: &[Pitch], key: &Key) -> Vec<Pitch> {
+    let scale = key.scale().notes();
+    let signature = key
+        .accidentals()
+        .first()
+        .map_or(1, |n| n.accidental().semitone_offset().signum());
+    let semitones: Vec<i16> = pitches.iter().map(|p| p.midi_number()).collect();
+
+    pitches
+        .iter()
+        .enumerate()
+        .map(|(i, &pitch)| {
+            if let Some(&name) = scale.iter().find(|n| n.is_enharmonic_with(&pitch.name())) {
+                return Pitch::from_midi_number(name, semitones[i]);
+            }
+
+            let next = semitones.get(i + 1).map(|&s| (s - semitones[i]).signum());
+            let previous = i
+                .checked_sub(1)
+                .map(|j| (semitones[i] - semitones[j]).signum());
+            let direction = [next, previous]
+                .into_iter()
+                .flatten()
+                .find(|&d| d != 0)
+                .unwrap_or(signature as i16);
+            ChromaticTransposer::spell(pitch, 0, direction as i8)
+        })
+        .collect()
+}
+
+/// Counts pitch classes that have to share a letter with another pitch class
+fn letter_clashes(pitches: &[Pitch]) -> usize {
+    let mut names: Vec<NoteName> = Vec::new();
+    for pitch in pitches {
+        if !names.iter().any(|n| n.is_enharmonic_with(&pitch.name())) {
+            names.push(pitch.name());
+        }
+    }
+
+    let mut letters: Vec<Letter> = names.iter().map(|n| n.letter()).collect();
+    letters.sort_by_key(|l| l.base_midi_number());
+    letters.dedup();
+    names.len() - letters.len()
+}
+
+fn accidental_count(pitches: &[Pitch]) -> usize {
+    pitches
+        .iter()
+        .map(|p| p.name().accidental().semitone_offset().unsigned_abs() as usize)
+        .sum()
+}
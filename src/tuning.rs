@@ -0,0 +1,56 @@
+use crate::types::{NoteName, Pitch};
+
+/// Five-limit just ratios for each semitone above the tonic
+///
+/// Each ratio comes from the lowest harmonics that give the interval: the
+/// fifth is 3:2, the major third 5:4, and the rest are built from those.
+const JUST_RATIOS: [(u32, u32); 12] = [
+    (1, 1),   // unison
+    (16, 15), // minor second
+    (9, 8),   // major second
+    (6, 5),   // minor third
+    (5, 4),   // major third
+    (4, 3),   // perfect fourth
+    (45, 32), // augmented fourth
+    (3, 2),   // perfect fifth
+    (8, 5),   // minor sixth
+    (5, 3),   // major sixth
+    (9, 5),   // minor seventh
+    (15, 8),  // major seventh
+];
+
+/// A way of turning pitches into frequencies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tuning {
+    /// Twelve equal semitones per octave, with A4 at 440 Hz
+    EqualTemperament,
+    /// Pure ratios above `tonic`, which itself keeps its equal-tempered frequency
+    JustIntonation { tonic: NoteName },
+}
+
+impl Tuning {
+    /// Returns the frequency of a pitch in hertz
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{note, pitch};
+    /// use chordy::tuning::Tuning;
+    ///
+    /// let just = Tuning::JustIntonation { tonic: note!("C") };
+    /// let c4 = just.frequency(pitch!("C4"));
+    /// assert!((just.frequency(pitch!("G4")) - c4 * 1.5).abs() < 1e-9);
+    /// ```
+    pub fn frequency(&self, pitch: Pitch) -> f64 {
+        match self {
+            Tuning::EqualTemperament => pitch.to_frequency(),
+            Tuning::JustIntonation { tonic } => {
+                let above =
+                    (pitch.name().base_midi_number() - tonic.base_midi_number()).rem_euclid(12);
+                let tonic = pitch.transpose(-above).to_frequency();
+                let (numerator, denominator) = JUST_RATIOS[above as usize];
+                tonic * numerator as f64 / denominator as f64
+            }
+        }
+    }
+}
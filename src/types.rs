@@ -1,6 +1,14 @@
-use std::{fmt, str::FromStr};
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Index, Neg, Sub},
+    str::FromStr,
+    sync::OnceLock,
+};
 
 use crate::error::ParseError;
+use crate::scales::{self, ScaleDefinition};
+use crate::transposition::{ChromaticTransposer, Transposer};
 
 /// Represents a musical note name with a letter and accidental
 ///
@@ -24,111 +32,3708 @@ pub struct NoteName {
     accidental: Accidental,
 }
 
+impl Default for NoteName {
+    /// Returns C natural
+    fn default() -> Self {
+        NoteName::C
+    }
+}
+
 impl NoteName {
-    pub fn new(letter: Letter, accidental: Accidental) -> Self {
+    pub const C: NoteName = NoteName::new(Letter::C, Accidental::Natural);
+    pub const D: NoteName = NoteName::new(Letter::D, Accidental::Natural);
+    pub const E: NoteName = NoteName::new(Letter::E, Accidental::Natural);
+    pub const F: NoteName = NoteName::new(Letter::F, Accidental::Natural);
+    pub const G: NoteName = NoteName::new(Letter::G, Accidental::Natural);
+    pub const A: NoteName = NoteName::new(Letter::A, Accidental::Natural);
+    pub const B: NoteName = NoteName::new(Letter::B, Accidental::Natural);
+
+    pub const fn new(letter: Letter, accidental: Accidental) -> Self {
         NoteName { letter, accidental }
     }
 
-    /// Returns the MIDI note number for this note name in octave 0
+    pub fn letter(&self) -> Letter {
+        self.letter
+    }
+
+    pub fn accidental(&self) -> Accidental {
+        self.accidental
+    }
+
+    /// Returns the position of this note on the line of fifths, with C at 0
+    ///
+    /// Moving one step up adds a perfect fifth (G = 1, D = 2), moving one step
+    /// down subtracts one (F = -1). Each sharp adds 7 and each flat subtracts 7.
+    pub fn fifths(&self) -> i8 {
+        self.letter.fifths() + 7 * self.accidental.semitone_offset()
+    }
+
+    /// Returns the enharmonic equivalent of this note spelled with the given accidental
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental};
+    ///
+    /// let g_sharp = NoteName::new(Letter::G, Accidental::Sharp);
+    /// let a_flat = NoteName::new(Letter::A, Accidental::Flat);
+    /// assert_eq!(g_sharp.respell_as(Accidental::Flat), Some(a_flat));
+    /// assert_eq!(g_sharp.respell_as(Accidental::Natural), None);
+    /// ```
+    pub fn respell_as(&self, accidental: Accidental) -> Option<NoteName> {
+        Letter::all()
+            .into_iter()
+            .map(|letter| NoteName::new(letter, accidental))
+            .find(|candidate| candidate.is_enharmonic_with(self))
+    }
+
+    /// Builds the note at a position on the line of fifths
+    ///
+    /// Positions that would need more than a double accidental are respelled
+    /// on a neighbouring letter, which keeps the pitch class.
+    pub(crate) fn from_fifths(mut fifths: i16) -> Self {
+        while fifths > 19 {
+            fifths -= 12;
+        }
+        while fifths < -15 {
+            fifths += 12;
+        }
+
+        let letter = Letter::from_fifths((fifths + 1).rem_euclid(7) as i8 - 1);
+        let shift = (fifths + 1).div_euclid(7) as i8;
+        NoteName::new(letter, Accidental::from_shift(shift))
+    }
+
+    /// Returns the MIDI note number for this note name in octave 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental};
+    /// let c_natural = NoteName::new(Letter::C, Accidental::Natural);
+    /// assert_eq!(c_natural.base_midi_number(), 0);
+    ///
+    /// let f_sharp = NoteName::new(Letter::F, Accidental::Sharp);
+    /// assert_eq!(f_sharp.base_midi_number(), 6);
+    ///
+    /// let b_flat = NoteName::new(Letter::B, Accidental::Flat);
+    /// assert_eq!(b_flat.base_midi_number(), 10);
+    /// ```
+    ///
+    pub fn base_midi_number(&self) -> i8 {
+        self.letter.base_midi_number() + self.accidental.semitone_offset()
+    }
+
+    /// Returns the number of letter steps up from this note to another
+    ///
+    /// The distance is always counted upwards and ignores accidentals, so it
+    /// is in the range 0–6: C to E is 2, C to B is 6 and B to C is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental};
+    ///
+    /// let c = NoteName::new(Letter::C, Accidental::Natural);
+    /// let e_flat = NoteName::new(Letter::E, Accidental::Flat);
+    /// assert_eq!(c.letter_distance_to(&e_flat), 2);
+    /// assert_eq!(e_flat.letter_distance_to(&c), 5);
+    /// ```
+    pub fn letter_distance_to(&self, other: &NoteName) -> i8 {
+        (4 * (other.letter.fifths() - self.letter.fifths())).rem_euclid(7)
+    }
+
+    /// Returns the ascending interval from this note up to another, within an octave
+    ///
+    /// This is `*other - *self`: note names have no octave, so the interval
+    /// always goes up and is smaller than an octave.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Interval};
+    ///
+    /// assert_eq!(NoteName::G.interval_to(&NoteName::C), Interval::PERFECT_FOURTH);
+    /// ```
+    pub fn interval_to(&self, other: &NoteName) -> Interval {
+        *other - *self
+    }
+
+    /// Moves the note up or down by a number of scale degrees
+    ///
+    /// The note is looked up in the scale enharmonically and the result is
+    /// spelled as the scale spells it, wrapping around past the octave. Notes
+    /// that are not in the scale return `None` rather than snapping to a
+    /// neighbouring degree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Scale, ScaleType};
+    ///
+    /// let n = |letter| NoteName::new(letter, Accidental::Natural);
+    /// let c_major = Scale::new(n(Letter::C), ScaleType::Major);
+    /// assert_eq!(n(Letter::E).transpose_diatonic(&c_major, 2), Some(n(Letter::G)));
+    /// assert_eq!(n(Letter::E).transpose_diatonic(&c_major, -1), Some(n(Letter::D)));
+    /// ```
+    pub fn transpose_diatonic(&self, scale: &Scale, degrees: i8) -> Option<NoteName> {
+        let degree = scale.degree_of(self)?;
+        let notes = scale.notes_cached();
+        let index = (degree as isize - 1 + degrees as isize).rem_euclid(notes.len() as isize);
+        Some(notes[index as usize])
+    }
+
+    /// Returns the pitch class of the note, from 0 for C to 11 for B
+    ///
+    /// Accidentals that cross the octave wrap around, so C♭ is 11 and B♯ is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental};
+    ///
+    /// assert_eq!(NoteName::new(Letter::D, Accidental::Flat).pitch_class(), 1);
+    /// assert_eq!(NoteName::new(Letter::C, Accidental::Flat).pitch_class(), 11);
+    /// ```
+    pub fn pitch_class(&self) -> u8 {
+        self.base_midi_number().rem_euclid(12) as u8
+    }
+
+    /// Checks if two note names are enharmonically equivalent
+    pub fn is_enharmonic_with(&self, other: &Self) -> bool {
+        // Notes are enharmonically equivalent if they represent the same pitch
+        self.pitch_class() == other.pitch_class()
+    }
+
+    /// Raises the note by a chromatic semitone, keeping its letter
+    ///
+    /// Unlike adding a minor second, the letter never changes, so C becomes
+    /// C♯ rather than D♭. Returns `None` for double sharps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::note;
+    ///
+    /// assert_eq!(note!("C").raised(), Some(note!("C#")));
+    /// assert_eq!(note!("C##").raised(), None);
+    /// ```
+    pub fn raised(&self) -> Option<NoteName> {
+        Some(NoteName::new(self.letter, self.accidental.raised()?))
+    }
+
+    /// Lowers the note by a chromatic semitone, keeping its letter
+    ///
+    /// Returns `None` for double flats.
+    pub fn lowered(&self) -> Option<NoteName> {
+        Some(NoteName::new(self.letter, self.accidental.lowered()?))
+    }
+
+    /// Places the note in an octave
+    pub fn to_pitch(&self, octave: i8) -> Pitch {
+        Pitch::new(*self, octave)
+    }
+
+    /// Returns the equal-tempered frequency of the note in an octave, with A4 at 440 Hz
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{note, Pitch};
+    ///
+    /// assert_eq!(note!("A").frequency_at(4), 440.0);
+    /// assert_eq!(note!("C").frequency_at(4), Pitch::new(note!("C"), 4).to_frequency());
+    /// ```
+    pub fn frequency_at(&self, octave: i8) -> f64 {
+        self.to_pitch(octave).to_frequency()
+    }
+
+    /// Returns the equal-tempered frequency of the note in an octave, tuned to `a4_hz`
+    pub fn frequency_at_with(&self, octave: i8, a4_hz: f64) -> f64 {
+        self.to_pitch(octave).to_frequency_with(a4_hz)
+    }
+}
+
+impl Add<Interval> for NoteName {
+    type Output = NoteName;
+
+    fn add(self, interval: Interval) -> NoteName {
+        // Summed in i16, since parsed intervals can reach ±127 fifths
+        NoteName::from_fifths(self.fifths() as i16 + interval.fifths() as i16)
+    }
+}
+
+/// Note names are ordered by their position on the line of fifths
+///
+/// This is key-signature order, not pitch order: F < C < G < D, and every
+/// flat comes before every natural. Use [`NoteName::cmp_by_pitch_class`] to
+/// order by pitch instead.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{note, NoteName};
+///
+/// assert!(NoteName::C < NoteName::G && NoteName::G < NoteName::D);
+/// assert!(note!("Bb") < NoteName::F);
+/// ```
+impl Ord for NoteName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.fifths().cmp(&other.fifths())
+    }
+}
+
+impl PartialOrd for NoteName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl NoteName {
+    /// Compares two note names by pitch class, from C up to B
+    ///
+    /// Enharmonic notes have the same pitch class and are ordered by the line
+    /// of fifths, flatter spelling first, so the order is consistent with
+    /// equality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{note, NoteName};
+    ///
+    /// let mut notes = vec![NoteName::D, note!("C#"), NoteName::C, note!("Db")];
+    /// notes.sort_by(NoteName::cmp_by_pitch_class);
+    /// assert_eq!(notes, [NoteName::C, note!("Db"), note!("C#"), NoteName::D]);
+    /// ```
+    pub fn cmp_by_pitch_class(&self, other: &NoteName) -> Ordering {
+        self.pitch_class()
+            .cmp(&other.pitch_class())
+            .then_with(|| self.cmp(other))
+    }
+}
+
+impl Sub for NoteName {
+    type Output = Interval;
+
+    /// Returns the ascending interval from `other` up to `self`, within an octave
+    fn sub(self, other: NoteName) -> Interval {
+        let fifths = self.fifths() - other.fifths();
+        Interval::new(fifths, -(4 * fifths).div_euclid(7))
+    }
+}
+
+impl NoteName {
+    /// Returns the note name with an ASCII accidental ("C#", "Bb"), whatever the features
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental};
+    ///
+    /// let c_sharp = NoteName::new(Letter::C, Accidental::Sharp);
+    /// assert_eq!(c_sharp.to_string_ascii(), "C#");
+    /// assert_eq!(c_sharp.to_string_unicode(), "C♯");
+    /// ```
+    pub fn to_string_ascii(&self) -> String {
+        self.with_symbol(false)
+    }
+
+    /// Returns the note name with a Unicode accidental ("C♯", "B♭"), whatever the features
+    pub fn to_string_unicode(&self) -> String {
+        self.with_symbol(true)
+    }
+
+    fn with_symbol(&self, unicode: bool) -> String {
+        match self.accidental {
+            Accidental::Natural => self.letter.to_string(),
+            accidental => format!("{}{}", self.letter, accidental.symbol(unicode)),
+        }
+    }
+}
+
+impl fmt::Display for NoteName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.accidental {
+            Accidental::Natural => write!(f, "{}", self.letter),
+            _ => write!(f, "{}{}", self.letter, self.accidental),
+        }
+    }
+}
+
+impl FromStr for NoteName {
+    type Err = ParseError;
+
+    /// Parses a letter followed by an optional accidental, such as `C`,
+    /// `F#`, `B♭`, `Cn` or `E𝄫`
+    ///
+    /// This accepts exactly what the [`note!`](crate::note) macro accepts.
+    /// Whitespace is not trimmed, so `"C "` is an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::macros::parse_note(s).ok_or_else(|| ParseError::InvalidNoteName(s.to_string()))
+    }
+}
+
+/// A specific pitch with both note name and octave
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pitch {
+    name: NoteName,
+    octave: i8,
+}
+
+/// The octave of middle C, used for the default pitch and chord voicings
+const DEFAULT_OCTAVE: i8 = 4;
+
+impl Default for Pitch {
+    /// Returns C4, the middle C of the ABC and LilyPond output
+    fn default() -> Self {
+        Pitch::new(NoteName::default(), DEFAULT_OCTAVE)
+    }
+}
+
+impl Pitch {
+    pub const fn new(name: NoteName, octave: i8) -> Self {
+        Pitch { name, octave }
+    }
+
+    pub fn name(&self) -> NoteName {
+        self.name
+    }
+
+    pub fn octave(&self) -> i8 {
+        self.octave
+    }
+
+    /// Creates a pitch from a MIDI note number
+    ///
+    /// This is the inverse of [`Pitch::midi_number`], so note 60 is C4. Black
+    /// keys are spelled as C♯, E♭, F♯, A♭ and B♭.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Pitch};
+    ///
+    /// let pitch = Pitch::from_midi(70);
+    /// assert_eq!(pitch, Pitch::new(NoteName::new(Letter::B, Accidental::Flat), 4));
+    /// ```
+    pub fn from_midi(number: u8) -> Pitch {
+        let (letter, accidental) = match number % 12 {
+            0 => (Letter::C, Accidental::Natural),
+            1 => (Letter::C, Accidental::Sharp),
+            2 => (Letter::D, Accidental::Natural),
+            3 => (Letter::E, Accidental::Flat),
+            4 => (Letter::E, Accidental::Natural),
+            5 => (Letter::F, Accidental::Natural),
+            6 => (Letter::F, Accidental::Sharp),
+            7 => (Letter::G, Accidental::Natural),
+            8 => (Letter::A, Accidental::Flat),
+            9 => (Letter::A, Accidental::Natural),
+            10 => (Letter::B, Accidental::Flat),
+            _ => (Letter::B, Accidental::Natural),
+        };
+        Pitch::new(NoteName::new(letter, accidental), (number / 12) as i8 - 1)
+    }
+
+    /// Returns the full MIDI note number for this pitch
+    ///
+    /// Octaves follow scientific pitch notation, so middle C (C4) is note 60.
+    /// Pitches outside the MIDI range give numbers below 0 or above 127.
+    pub fn midi_number(&self) -> i16 {
+        // MIDI octaves start at -1, where C-1 is note 0
+        self.name.base_midi_number() as i16 + ((self.octave as i16 + 1) * 12)
+    }
+
+    /// Checks if two pitches represent the same frequency
+    pub fn is_enharmonic_with(&self, other: &Self) -> bool {
+        self.midi_number() == other.midi_number()
+    }
+
+    /// Checks whether the pitch has a MIDI note number, from C-1 (0) to G9 (127)
+    pub fn is_in_midi_range(&self) -> bool {
+        (0..=127).contains(&self.midi_number())
+    }
+
+    /// Returns the equal-tempered frequency in hertz, with A4 at 440 Hz
+    pub fn to_frequency(&self) -> f64 {
+        self.to_frequency_with(440.0)
+    }
+
+    /// Returns the equal-tempered frequency in hertz, tuned to `a4_hz`
+    ///
+    /// The frequency is built from [`Pitch::midi_number`], with A4 as note 69,
+    /// so [`Pitch::from_midi`] pitches sound at their MIDI frequency. Pitches
+    /// outside the MIDI range are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Pitch};
+    ///
+    /// assert_eq!(Pitch::new(NoteName::A, 5).to_frequency_with(432.0), 864.0);
+    /// assert_eq!(Pitch::from_midi(69).to_frequency_with(432.0), 432.0);
+    /// ```
+    pub fn to_frequency_with(&self, a4_hz: f64) -> f64 {
+        let semitones = self.midi_number() - 69;
+        a4_hz * 2f64.powf(semitones as f64 / 12.0)
+    }
+
+    /// Returns the signed distance to another pitch in cents
+    ///
+    /// Positive when `other` is higher; an equal-tempered semitone is 100 cents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::pitch;
+    ///
+    /// assert!((pitch!("C4").cents_to(&pitch!("C#4")) - 100.0).abs() < 1e-9);
+    /// ```
+    pub fn cents_to(&self, other: &Pitch) -> f64 {
+        cents_between(self.to_frequency(), other.to_frequency())
+    }
+
+    /// Moves the pitch by whole octaves until it lies in the MIDI range
+    ///
+    /// The note name is kept, so the result has the same pitch class and
+    /// is as close as possible to the original. Pitches already in range are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Pitch};
+    ///
+    /// let high = Pitch::new(NoteName::new(Letter::A, Accidental::Natural), 12);
+    /// assert!(!high.is_in_midi_range());
+    /// assert_eq!(high.clamp_midi(), Pitch::new(NoteName::new(Letter::A, Accidental::Natural), 8));
+    /// ```
+    pub fn clamp_midi(&self) -> Pitch {
+        // midi_number is an i16, so even the extreme octaves can't overflow
+        let semitones = self.midi_number();
+        let octaves = if semitones < 0 {
+            (-semitones + 11) / 12
+        } else if semitones > 127 {
+            -((semitones - 127 + 11) / 12)
+        } else {
+            0
+        };
+        Pitch::new(self.name, (self.octave as i16 + octaves) as i8)
+    }
+
+    /// Creates the pitch with the given name and MIDI number, which may lie outside the MIDI range
+    ///
+    /// Octaves beyond what an `i8` holds saturate at octave -128 or 127
+    /// rather than wrapping around.
+    pub(crate) fn from_midi_number(name: NoteName, number: i16) -> Pitch {
+        let octave = (number - name.base_midi_number() as i16).div_euclid(12) - 1;
+        Pitch::new(name, octave.clamp(i8::MIN as i16, i8::MAX as i16) as i8)
+    }
+
+    /// Moves the pitch by a number of semitones, spelled by the [`ChromaticTransposer`]
+    ///
+    /// Results above octave 127 or below octave -128 stay in that octave.
+    pub fn transpose(&self, semitones: i8) -> Pitch {
+        ChromaticTransposer.transpose(*self, semitones)
+    }
+
+    /// Iterates over every chromatic pitch from `start` to `end`, inclusive
+    ///
+    /// Descending ranges are supported. Pitches in between are spelled by the
+    /// [`ChromaticTransposer`], so rising runs use sharps and falling runs use
+    /// flats; the final pitch is `end` as given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Pitch};
+    ///
+    /// let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    /// let e4 = Pitch::new(NoteName::new(Letter::E, Accidental::Natural), 4);
+    /// let run: Vec<String> = Pitch::range(c4, e4).map(|p| p.to_string()).collect();
+    /// assert_eq!(run, ["C4", "C♯4", "D4", "D♯4", "E4"]);
+    /// ```
+    pub fn range(start: Pitch, end: Pitch) -> impl Iterator<Item = Pitch> {
+        let distance = end.midi_number() - start.midi_number();
+        let steps = distance.unsigned_abs() as usize;
+        let step = distance.signum() as i8;
+
+        std::iter::successors(Some(start), move |p| Some(p.transpose(step)))
+            .take(steps + 1)
+            .enumerate()
+            .map(move |(i, p)| if i == steps { end } else { p })
+    }
+
+    /// Moves the pitch by a spelled interval, keeping track of the octave
+    ///
+    /// Unlike [`Pitch::transpose`], the spelling follows the interval: an
+    /// augmented fourth and a diminished fifth both move six semitones, but
+    /// land on different letters. Results above octave 127 or below octave
+    /// -128 stay in that octave, as in [`Pitch::transpose`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Pitch, Interval};
+    ///
+    /// let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    /// let f_sharp4 = Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4);
+    /// assert_eq!(c4.transpose_by_interval(Interval::AUGMENTED_FOURTH), f_sharp4);
+    /// ```
+    pub fn transpose_by_interval(&self, interval: Interval) -> Pitch {
+        let name = self.name + interval;
+        Pitch::from_midi_number(name, self.midi_number() + interval.semitones() as i16)
+    }
+
+    /// Renders the pitch in ABC notation
+    ///
+    /// ABC writes middle C (C4) as `C` and the octave above as `c`. Lower
+    /// octaves add commas, higher ones add apostrophes, and accidentals are
+    /// written before the letter (`^` sharp, `_` flat).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Pitch};
+    ///
+    /// let f_sharp = Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 5);
+    /// assert_eq!(f_sharp.to_abc(), "^f");
+    ///
+    /// let b_flat = Pitch::new(NoteName::new(Letter::B, Accidental::Flat), 2);
+    /// assert_eq!(b_flat.to_abc(), "_B,,");
+    /// ```
+    pub fn to_abc(&self) -> String {
+        let accidental = match self.name.accidental {
+            Accidental::DoubleFlat => "__",
+            Accidental::Flat => "_",
+            Accidental::Natural => "",
+            Accidental::Sharp => "^",
+            Accidental::DoubleSharp => "^^",
+        };
+        let letter = self.name.letter.to_string();
+
+        // Counted in i16, since 4 - octave overflows an i8 for the lowest octaves
+        let octave = self.octave as i16;
+        if octave >= 5 {
+            let marks = "'".repeat((octave - 5) as usize);
+            format!("{}{}{}", accidental, letter.to_lowercase(), marks)
+        } else {
+            let marks = ",".repeat((4 - octave) as usize);
+            format!("{}{}{}", accidental, letter, marks)
+        }
+    }
+
+    /// Renders the pitch in LilyPond's absolute pitch syntax
+    ///
+    /// LilyPond writes C3 as `c` and middle C (C4) as `c'`. Each octave above
+    /// adds an apostrophe, each octave below adds a comma, and accidentals are
+    /// spelled with the Dutch `is`/`es` suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Pitch};
+    ///
+    /// let c_sharp = Pitch::new(NoteName::new(Letter::C, Accidental::Sharp), 4);
+    /// assert_eq!(c_sharp.to_lilypond(), "cis'");
+    ///
+    /// let b_flat = Pitch::new(NoteName::new(Letter::B, Accidental::Flat), 1);
+    /// assert_eq!(b_flat.to_lilypond(), "bes,,");
+    /// ```
+    pub fn to_lilypond(&self) -> String {
+        let accidental = match self.name.accidental {
+            Accidental::DoubleFlat => "eses",
+            Accidental::Flat => "es",
+            Accidental::Natural => "",
+            Accidental::Sharp => "is",
+            Accidental::DoubleSharp => "isis",
+        };
+        // Counted in i16, as in `to_abc`
+        let octave = self.octave as i16;
+        let marks = if octave >= 3 {
+            "'".repeat((octave - 3) as usize)
+        } else {
+            ",".repeat((3 - octave) as usize)
+        };
+
+        format!(
+            "{}{}{}",
+            self.name.letter.to_string().to_lowercase(),
+            accidental,
+            marks
+        )
+    }
+}
+
+/// Pitches are ordered by height (their MIDI number)
+///
+/// Enharmonic pitches such as C♯4 and D♭4 have the same height. They are still
+/// different pitches, so they are ordered by their position on the line of
+/// fifths: the flatter spelling comes first.
+impl Ord for Pitch {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.midi_number()
+            .cmp(&other.midi_number())
+            .then_with(|| self.name.fifths().cmp(&other.name.fifths()))
+    }
+}
+
+impl PartialOrd for Pitch {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Pitch {
+    /// Returns the spelled interval from this pitch to another, including whole octaves
+    ///
+    /// This is `*other - *self`, so moving down gives a descending interval.
+    /// It mirrors [`NoteName::interval_to`], which has no octaves to count and
+    /// always goes up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Pitch, Interval};
+    ///
+    /// let c4 = Pitch::new(NoteName::C, 4);
+    /// let e5 = Pitch::new(NoteName::E, 5);
+    /// assert_eq!(c4.interval_to(&e5), Interval::MAJOR_THIRD + Interval::PERFECT_OCTAVE);
+    /// assert_eq!(c4.interval_to(&e5).to_string(), "M10");
+    /// assert_eq!(e5.interval_to(&c4).to_string(), "-M10");
+    /// ```
+    pub fn interval_to(&self, other: &Pitch) -> Interval {
+        *other - *self
+    }
+}
+
+impl Sub for Pitch {
+    type Output = Interval;
+
+    /// Returns the spelled interval from `other` to `self`, including whole octaves
+    ///
+    /// The result is negative when `self` is the lower pitch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Pitch, Interval};
+    ///
+    /// let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    /// let g5 = Pitch::new(NoteName::new(Letter::G, Accidental::Natural), 5);
+    /// assert_eq!(g5 - c4, Interval::PERFECT_FIFTH + Interval::PERFECT_OCTAVE);
+    /// ```
+    fn sub(self, other: Pitch) -> Interval {
+        let fifths = self.name.fifths() - other.name.fifths();
+        let semitones = self.midi_number() - other.midi_number();
+        let octaves = (semitones - 7 * fifths as i16) / 12;
+        Interval::new(fifths, octaves as i8)
+    }
+}
+
+/// Returns the signed distance from `hz1` to `hz2` in cents, 1200 × log2(hz2 / hz1)
+///
+/// # Examples
+///
+/// ```
+/// use chordy::cents_between;
+///
+/// assert_eq!(cents_between(440.0, 880.0), 1200.0);
+/// assert_eq!(cents_between(440.0, 220.0), -1200.0);
+/// ```
+pub fn cents_between(hz1: f64, hz2: f64) -> f64 {
+    1200.0 * (hz2 / hz1).log2()
+}
+
+impl Pitch {
+    /// Returns the pitch with an ASCII accidental ("C#4"), whatever the features
+    pub fn to_string_ascii(&self) -> String {
+        format!("{}{}", self.name.to_string_ascii(), self.octave)
+    }
+
+    /// Returns the pitch with a Unicode accidental ("C♯4"), whatever the features
+    pub fn to_string_unicode(&self) -> String {
+        format!("{}{}", self.name.to_string_unicode(), self.octave)
+    }
+}
+
+impl fmt::Display for Pitch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.name, self.octave)
+    }
+}
+
+impl FromStr for Pitch {
+    type Err = ParseError;
+
+    /// Parses a note name followed by an octave number, such as `A4`,
+    /// `C#5` or `B♭-1`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::UnrecognizedFormat(s.to_string());
+
+        let split = s
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_ascii_digit() || *c == '-')
+            .map(|(i, _)| i)
+            .ok_or_else(invalid)?;
+        let (name, octave) = s.split_at(split);
+        let octave = octave.parse().map_err(|_| invalid())?;
+        Ok(Pitch::new(name.parse()?, octave))
+    }
+}
+
+/// A chord with a root note and the intervals of its tones above the root
+///
+/// Chords store their tones rather than a quality plus a list of
+/// [`ChordExtension`]s. Tones such as a sharp eleventh or an omitted fifth
+/// are then ordinary intervals, and each tone keeps its spelling because
+/// [`Interval`] counts fifths instead of semitones. The quality is worked out
+/// from the tones with [`Chord::quality`], and extensions are applied with
+/// [`Chord::with_extension`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chord {
+    root: NoteName,
+    intervals: Vec<Interval>,
+}
+
+impl fmt::Display for Chord {
+    /// Writes the chord symbol ("Cmaj7", "Am"), or with `{:#}` the full
+    /// name ("C major seventh", "A minor")
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return write!(f, "{}", self.abbreviated_name());
+        }
+
+        let has_minor_seventh = self
+            .intervals
+            .iter()
+            .any(|i| i.simple() == Interval::MINOR_SEVENTH);
+        match (self.extended_type(), self.quality()) {
+            (Some(seventh_type), _) => write!(f, "{} {}", self.root, seventh_type.full_name()),
+            (None, ChordQuality::Sus4 | ChordQuality::Sus2) if has_minor_seventh => {
+                write!(f, "{} seventh {}", self.root, self.quality().full_name())
+            }
+            (None, quality) if has_minor_seventh => {
+                write!(f, "{} {} seventh", self.root, quality.full_name())
+            }
+            (None, quality) => write!(f, "{} {}", self.root, quality.full_name()),
+        }
+    }
+}
+
+impl FromStr for Chord {
+    type Err = ParseError;
+
+    /// Parses a chord symbol in the form written by [`Chord::abbreviated_name`]
+    ///
+    /// The root is a note name, followed by the quality (`m`, `°`, `ø`, `+`,
+    /// or `min`, `dim`, `aug`), the seventh or highest extension (`7`,
+    /// `maj9`, `13`), or `6`, `6/9`, `add9` for chords without a seventh,
+    /// then `sus4` or `sus2`. Altered tones and omissions follow in
+    /// parentheses: `C7(♭9)`, `G7(♯5♯9)`, `Cmaj9(no3)`. ASCII `b` and `#`
+    /// work for the alterations as well.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidChordSymbol(s.to_string());
+
+        // The root is the longest prefix that reads as a note name
+        let (root, suffix) = (1..=s.len())
+            .rev()
+            .filter(|&i| s.is_char_boundary(i))
+            .find_map(|i| s[..i].parse::<NoteName>().ok().map(|root| (root, &s[i..])))
+            .ok_or_else(invalid)?;
+
+        // "m(maj7)" keeps its parentheses; other groups hold alterations
+        let groups_start = suffix
+            .match_indices('(')
+            .map(|(i, _)| i)
+            .find(|&i| !suffix[i..].starts_with("(maj"))
+            .unwrap_or(suffix.len());
+        let (body, groups) = suffix.split_at(groups_start);
+
+        let mut chord = parse_chord_body(root, body).ok_or_else(invalid)?;
+
+        let mut groups = groups;
+        while !groups.is_empty() {
+            let inner = groups.strip_prefix('(').ok_or_else(invalid)?;
+            let end = inner.find(')').ok_or_else(invalid)?;
+            chord = apply_chord_group(chord, &inner[..end]).ok_or_else(invalid)?;
+            groups = &inner[end + 1..];
+        }
+        Ok(chord)
+    }
+}
+
+/// Parses the quality, extension and suspension of a chord symbol
+fn parse_chord_body(root: NoteName, body: &str) -> Option<Chord> {
+    let diminished = [
+        ("°7", SeventhType::Diminished),
+        ("dim7", SeventhType::Diminished),
+        ("ø7", SeventhType::HalfDiminished),
+        ("ø", SeventhType::HalfDiminished),
+        ("m7♭5", SeventhType::HalfDiminished),
+        ("m7b5", SeventhType::HalfDiminished),
+    ];
+    if let Some(&(_, seventh_type)) = diminished.iter().find(|(name, _)| *name == body) {
+        return Some(Chord::seventh(root, seventh_type));
+    }
+    match body {
+        "5" => return Some(Chord::major(root).remove_interval(Interval::MAJOR_THIRD)),
+        "°" | "dim" => return Some(Chord::diminished(root)),
+        "°(maj7)" => return Some(Chord::diminished(root).add_interval(Interval::MAJOR_SEVENTH)),
+        _ => {}
+    }
+
+    let (quality, rest) = if body.starts_with("maj") {
+        (ChordQuality::Major, body)
+    } else if let Some(rest) = body.strip_prefix("min").or_else(|| body.strip_prefix('m')) {
+        (ChordQuality::Minor, rest)
+    } else if let Some(rest) = body.strip_prefix("aug").or_else(|| body.strip_prefix('+')) {
+        (ChordQuality::Augmented, rest)
+    } else {
+        (ChordQuality::Major, body)
+    };
+
+    let (rest, suspension) = if let Some(rest) = rest.strip_suffix("sus4") {
+        (rest, Some(Interval::PERFECT_FOURTH))
+    } else if let Some(rest) = rest.strip_suffix("sus2") {
+        (rest, Some(Interval::MAJOR_SECOND))
+    } else if let Some(rest) = rest.strip_suffix("sus") {
+        (rest, Some(Interval::PERFECT_FOURTH))
+    } else {
+        (rest, None)
+    };
+
+    let mut chord = Chord::new(root, quality);
+    let extension = rest
+        .strip_prefix("(maj")
+        .and_then(|r| r.strip_suffix(')'))
+        .or_else(|| rest.strip_prefix("maj"));
+    let (seventh, number) = match extension {
+        Some(number) => (Some(Interval::MAJOR_SEVENTH), number),
+        None if rest.is_empty() => (None, ""),
+        None => match rest {
+            "6" => (None, "6"),
+            "6/9" | "69" => (None, "6/9"),
+            "add9" | "add2" => (None, "add9"),
+            "add11" | "add4" => (None, "add11"),
+            "add13" => (None, "add13"),
+            number => (Some(Interval::MINOR_SEVENTH), number),
+        },
+    };
+
+    let added: &[Interval] = match number {
+        "" if seventh.is_none() => &[],
+        "6" => &[Interval::MAJOR_SIXTH],
+        "6/9" => &[Interval::MAJOR_SIXTH, Interval::MAJOR_NINTH],
+        "add9" => &[Interval::MAJOR_NINTH],
+        "add11" => &[Interval::PERFECT_ELEVENTH],
+        "add13" => &[Interval::MAJOR_THIRTEENTH],
+        "7" => &[],
+        "9" => &[Interval::MAJOR_NINTH],
+        "11" => &[Interval::MAJOR_NINTH, Interval::PERFECT_ELEVENTH],
+        "13" => &[Interval::MAJOR_NINTH, Interval::MAJOR_THIRTEENTH],
+        _ => return None,
+    };
+    if let Some(seventh) = seventh {
+        chord = chord.add_interval(seventh);
+    }
+    for &interval in added {
+        chord = chord.add_interval(interval);
+    }
+
+    if let Some(suspension) = suspension {
+        chord = chord
+            .remove_interval(Interval::MAJOR_THIRD)
+            .remove_interval(Interval::MINOR_THIRD)
+            .add_interval(suspension);
+    }
+    Some(chord)
+}
+
+/// Applies one parenthesised group of a chord symbol, like `♭9♯11` or `no3`
+fn apply_chord_group(chord: Chord, group: &str) -> Option<Chord> {
+    match group {
+        "no3" => {
+            return Some(
+                chord
+                    .remove_interval(Interval::MAJOR_THIRD)
+                    .remove_interval(Interval::MINOR_THIRD),
+            );
+        }
+        "no5" => return Some(chord.remove_interval(Interval::PERFECT_FIFTH)),
+        "" => return None,
+        _ => {}
+    }
+
+    // (number, natural tone, flat alteration, sharp alteration)
+    let alterations = [
+        (
+            "5",
+            Interval::PERFECT_FIFTH,
+            Interval::DIMINISHED_FIFTH,
+            Interval::AUGMENTED_FIFTH,
+        ),
+        (
+            "9",
+            Interval::MAJOR_NINTH,
+            Interval::MINOR_NINTH,
+            Interval::AUGMENTED_NINTH,
+        ),
+        (
+            "11",
+            Interval::PERFECT_ELEVENTH,
+            Interval::PERFECT_ELEVENTH,
+            Interval::AUGMENTED_ELEVENTH,
+        ),
+        (
+            "13",
+            Interval::MAJOR_THIRTEENTH,
+            Interval::MINOR_THIRTEENTH,
+            Interval::MAJOR_THIRTEENTH,
+        ),
+    ];
+
+    let mut chord = chord;
+    let mut rest = group;
+    while !rest.is_empty() {
+        let (sharp, after) = if let Some(after) =
+            rest.strip_prefix('♭').or_else(|| rest.strip_prefix('b'))
+        {
+            (false, after)
+        } else if let Some(after) = rest.strip_prefix('♯').or_else(|| rest.strip_prefix('#')) {
+            (true, after)
+        } else {
+            return None;
+        };
+        let digits = after
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(after.len());
+        let (number, after) = after.split_at(digits);
+        let &(_, natural, flat, sharp_interval) =
+            alterations.iter().find(|(n, ..)| *n == number)?;
+        let altered = if sharp { sharp_interval } else { flat };
+        if altered == natural {
+            return None;
+        }
+        chord = chord.remove_interval(natural).add_interval(altered);
+        rest = after;
+    }
+    Some(chord)
+}
+
+impl Default for Chord {
+    /// Returns a C major triad
+    ///
+    /// An empty chord would have no quality or name, so the default is the
+    /// simplest complete chord instead.
+    fn default() -> Self {
+        Chord::major(NoteName::default())
+    }
+}
+
+impl Chord {
+    /// Creates a triad of the given quality on a root
+    pub fn new(root: NoteName, quality: ChordQuality) -> Self {
+        Chord {
+            root,
+            intervals: quality.intervals().to_vec(),
+        }
+    }
+
+    pub fn major(root: NoteName) -> Self {
+        Chord::new(root, ChordQuality::Major)
+    }
+
+    pub fn minor(root: NoteName) -> Self {
+        Chord::new(root, ChordQuality::Minor)
+    }
+
+    pub fn diminished(root: NoteName) -> Self {
+        Chord::new(root, ChordQuality::Diminished)
+    }
+
+    pub fn augmented(root: NoteName) -> Self {
+        Chord::new(root, ChordQuality::Augmented)
+    }
+
+    /// Creates a seventh chord of the given type on a root
+    pub fn seventh(root: NoteName, seventh: SeventhType) -> Self {
+        Chord {
+            root,
+            intervals: seventh.intervals().to_vec(),
+        }
+    }
+
+    fn nashville_suffix(&self) -> &'static str {
+        let has = |interval| self.intervals.iter().any(|i| i.simple() == interval);
+
+        match (self.quality(), has(Interval::MINOR_SEVENTH)) {
+            (_, false) if has(Interval::MAJOR_SEVENTH) => match self.quality() {
+                ChordQuality::Minor => "m(maj7)",
+                _ => "maj7",
+            },
+            (ChordQuality::Diminished, false) if has(Interval::DIMINISHED_SEVENTH) => "°7",
+            (ChordQuality::Major, true) => "7",
+            (ChordQuality::Minor, true) => "m7",
+            (ChordQuality::Diminished, true) => "ø7",
+            (ChordQuality::Augmented, true) => "+7",
+            (ChordQuality::Sus4, true) => "7sus4",
+            (ChordQuality::Sus2, true) => "7sus2",
+            (ChordQuality::Major, false) => "",
+            (ChordQuality::Minor, false) => "m",
+            (ChordQuality::Diminished, false) => "°",
+            (ChordQuality::Augmented, false) => "+",
+            (ChordQuality::Sus4, false) => "sus4",
+            (ChordQuality::Sus2, false) => "sus2",
+        }
+    }
+
+    /// Returns the quality marks that follow a roman numeral (V7, viiø7, III+)
+    ///
+    /// Minor and major are carried by the numeral's case, so only the
+    /// remaining marks are spelled out here.
+    fn roman_numeral_suffix(&self) -> &'static str {
+        let has = |interval| self.intervals.iter().any(|i| i.simple() == interval);
+
+        match (self.quality(), has(Interval::MINOR_SEVENTH)) {
+            (ChordQuality::Augmented, false) if has(Interval::MAJOR_SEVENTH) => "+maj7",
+            (_, false) if has(Interval::MAJOR_SEVENTH) => "maj7",
+            (ChordQuality::Diminished, false) if has(Interval::DIMINISHED_SEVENTH) => "°7",
+            (ChordQuality::Major | ChordQuality::Minor, true) => "7",
+            (ChordQuality::Diminished, true) => "ø7",
+            (ChordQuality::Augmented, true) => "+7",
+            (ChordQuality::Sus4, true) => "7sus4",
+            (ChordQuality::Sus2, true) => "7sus2",
+            (ChordQuality::Major | ChordQuality::Minor, false) => "",
+            (ChordQuality::Diminished, false) => "°",
+            (ChordQuality::Augmented, false) => "+",
+            (ChordQuality::Sus4, false) => "sus4",
+            (ChordQuality::Sus2, false) => "sus2",
+        }
+    }
+
+    /// Returns the smallest transposition, in semitones, that maps the chord onto itself
+    ///
+    /// Only pitch classes are compared, so spelling is ignored. An augmented
+    /// triad repeats every 4 semitones and a diminished seventh every 3;
+    /// chords without such a symmetry return 12.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Chord};
+    ///
+    /// assert_eq!(Chord::augmented(NoteName::C).transposition_symmetry(), 4);
+    /// assert_eq!(Chord::major(NoteName::C).transposition_symmetry(), 12);
+    /// ```
+    pub fn transposition_symmetry(&self) -> u8 {
+        let bits = scales::ScaleBitmask::from_intervals(&self.intervals).bits();
+        (1..12)
+            .find(|&t| ((bits << t) | (bits >> (12 - t))) & 0x0FFF == bits)
+            .unwrap_or(12)
+    }
+
+    /// Checks whether some transposition short of an octave maps the chord onto itself
+    pub fn is_symmetric(&self) -> bool {
+        self.transposition_symmetry() < 12
+    }
+
+    /// Splits a six-note chord into two triads stacked on top of each other
+    ///
+    /// The lower triad is built on the chord's root and the upper triad from
+    /// the remaining three tones, returned as `(upper, lower)`. Chords that
+    /// are not made of exactly two such triads return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Chord, Interval};
+    ///
+    /// // C E G with D F♯ A above: C major 13♯11 without its seventh
+    /// let chord = Chord::major(NoteName::C)
+    ///     .add_interval(Interval::MAJOR_NINTH)
+    ///     .add_interval(Interval::AUGMENTED_ELEVENTH)
+    ///     .add_interval(Interval::MAJOR_THIRTEENTH);
+    /// let (upper, lower) = chord.as_polychord().unwrap();
+    /// assert_eq!(upper, Chord::major(NoteName::D));
+    /// assert_eq!(lower, Chord::major(NoteName::C));
+    /// ```
+    pub fn as_polychord(&self) -> Option<(Chord, Chord)> {
+        let notes = self.notes();
+        if notes.len() != 6 || notes[0] != self.root {
+            return None;
+        }
+
+        for a in 1..6 {
+            for b in a + 1..6 {
+                let Some(lower) = triad_from(self.root, notes[a], notes[b]) else {
+                    continue;
+                };
+                let rest: Vec<NoteName> = (1..6)
+                    .filter(|&i| i != a && i != b)
+                    .map(|i| notes[i])
+                    .collect();
+                let upper = triad_from(rest[0], rest[1], rest[2])
+                    .or_else(|| triad_from(rest[1], rest[0], rest[2]))
+                    .or_else(|| triad_from(rest[2], rest[0], rest[1]));
+                if let Some(upper) = upper {
+                    return Some((upper, lower));
+                }
+            }
+        }
+        None
+    }
+
+    /// Writes the chord as a polychord, upper triad over lower ("D/C")
+    ///
+    /// Returns `None` when [`Chord::as_polychord`] finds no split.
+    pub fn to_polychord_string(&self) -> Option<String> {
+        let (upper, lower) = self.as_polychord()?;
+        Some(format!(
+            "{}/{}",
+            upper.abbreviated_name(),
+            lower.abbreviated_name()
+        ))
+    }
+
+    /// Returns the figured-bass numerals for the chord over a bass note
+    ///
+    /// The figures are the generic intervals of the other chord tones above
+    /// the bass, abbreviated the usual way: a triad reads `5/3`, `6` or
+    /// `6/4`, a seventh chord `7`, `6/5`, `4/3` or `4/2`. Other combinations
+    /// list every figure from the top down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Chord};
+    ///
+    /// let c_major = Chord::major(NoteName::C);
+    /// assert_eq!(c_major.figured_bass(NoteName::E), "6");
+    /// assert_eq!(c_major.figured_bass(NoteName::G), "6/4");
+    /// ```
+    pub fn figured_bass(&self, bass: NoteName) -> String {
+        let mut figures: Vec<i8> = self
+            .notes()
+            .iter()
+            .map(|&note| (note - bass).simple().steps() + 1)
+            .filter(|&figure| figure != 1)
+            .collect();
+        figures.sort_unstable_by(|a, b| b.cmp(a));
+        figures.dedup();
+
+        match figures.as_slice() {
+            [5, 3] => "5/3".to_string(),
+            [6, 3] => "6".to_string(),
+            [6, 4] => "6/4".to_string(),
+            [7, 5, 3] => "7".to_string(),
+            [6, 5, 3] => "6/5".to_string(),
+            [6, 4, 3] => "4/3".to_string(),
+            [6, 4, 2] => "4/2".to_string(),
+            _ => figures
+                .iter()
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+                .join("/"),
+        }
+    }
+
+    /// Returns the chord's notes ordered from the most to the least stable
+    ///
+    /// Stability is the consonance of each tone with the root: the root and
+    /// fifth come first, then the third, sixths, fourth and ninth, then the
+    /// seventh, and altered tones last. Tones that rank equally keep their
+    /// order in the chord. Dropping notes from the end of the list gives the
+    /// usual reductions of a voicing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Chord, SeventhType};
+    ///
+    /// let g7 = Chord::seventh(NoteName::G, SeventhType::Dominant);
+    /// assert_eq!(g7.tones_by_stability(), [NoteName::G, NoteName::D, NoteName::B, NoteName::F]);
+    /// ```
+    pub fn tones_by_stability(&self) -> Vec<NoteName> {
+        let rank = |interval: Interval| match interval.simple() {
+            Interval::PERFECT_UNISON => 0,
+            Interval::PERFECT_FIFTH => 1,
+            Interval::MAJOR_THIRD | Interval::MINOR_THIRD => 2,
+            Interval::MAJOR_SIXTH | Interval::MINOR_SIXTH => 3,
+            Interval::PERFECT_FOURTH => 4,
+            Interval::MAJOR_SECOND => 5,
+            Interval::MINOR_SEVENTH | Interval::MAJOR_SEVENTH => 6,
+            _ => 7,
+        };
+
+        let mut intervals = self.intervals.clone();
+        intervals.sort_by_key(|&i| rank(i));
+        intervals.iter().map(|&i| self.root + i).collect()
+    }
+
+    /// Checks whether two chords have the same root and the same pitch classes
+    ///
+    /// Unlike `==`, which compares the interval lists element by element,
+    /// this ignores voicing: inversions and tones moved by octaves still
+    /// count as the same chord. Notes are compared by pitch class, so
+    /// enharmonic spellings also match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Chord, Invertible};
+    ///
+    /// let c_major = Chord::major(NoteName::C);
+    /// assert_ne!(c_major.inverted(1), c_major);
+    /// assert!(c_major.inverted(1).is_same_chord(&c_major));
+    /// ```
+    pub fn is_same_chord(&self, other: &Chord) -> bool {
+        self.root.pitch_class() == other.root.pitch_class()
+            && scales::ScaleBitmask::from_intervals(&self.intervals)
+                == scales::ScaleBitmask::from_intervals(&other.intervals)
+    }
+
+    /// Returns the chord symbol, such as "Cmaj7", "Am", "G7(♭9)" or "Cmaj9(no3)"
+    ///
+    /// The symbol is read from the chord tones: the third and fifth give the
+    /// quality, the seventh and the highest natural extension give the number
+    /// (`maj9`, `13`), and chords without a seventh use `6`, `add9` or `sus`.
+    /// Altered fifths and tensions follow in parentheses, and a missing third
+    /// or fifth is marked with [`OmittedNote`] suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Chord, Interval, SeventhType};
+    ///
+    /// assert_eq!(Chord::seventh(NoteName::A, SeventhType::Minor).abbreviated_name(), "Am7");
+    /// assert_eq!(Chord::diminished(NoteName::B).abbreviated_name(), "B°");
+    ///
+    /// let no_third = Chord::seventh(NoteName::C, SeventhType::Major)
+    ///     .remove_interval(Interval::MAJOR_THIRD)
+    ///     .add_interval(Interval::MAJOR_NINTH);
+    /// assert_eq!(no_third.abbreviated_name(), "Cmaj9(no3)");
+    /// ```
+    pub fn abbreviated_name(&self) -> String {
+        format!("{}{}", self.root, self.symbol_suffix())
+    }
+
+    /// Returns the chord tones that are missing from the chord
+    ///
+    /// The third counts as present when the chord is suspended (it has a
+    /// fourth, or a second and no seventh), since the suspension replaces it.
+    /// Any perfect, diminished or augmented fifth counts as a fifth.
+    pub fn omitted_notes(&self) -> Vec<OmittedNote> {
+        let has = |interval| self.intervals.iter().any(|i| i.simple() == interval);
+
+        let third = has(Interval::MAJOR_THIRD) || has(Interval::MINOR_THIRD);
+        let seventh = has(Interval::MINOR_SEVENTH) || has(Interval::MAJOR_SEVENTH);
+        let suspended = has(Interval::PERFECT_FOURTH) || (has(Interval::MAJOR_SECOND) && !seventh);
+        let fifth = has(Interval::PERFECT_FIFTH)
+            || has(Interval::DIMINISHED_FIFTH)
+            || has(Interval::AUGMENTED_FIFTH);
+
+        let mut omitted = Vec::new();
+        if !third && !suspended {
+            omitted.push(OmittedNote::No3);
+        }
+        if !fifth {
+            omitted.push(OmittedNote::No5);
+        }
+        omitted
+    }
+
+    fn symbol_suffix(&self) -> String {
+        use crate::symbols::{FLAT, SHARP};
+
+        let has = |interval| self.intervals.iter().any(|i| i.simple() == interval);
+
+        if self.intervals.len() == 2 && has(Interval::PERFECT_FIFTH) {
+            return "5".to_string();
+        }
+
+        let major_third = has(Interval::MAJOR_THIRD);
+        let minor_third = has(Interval::MINOR_THIRD) && !major_third;
+        let perfect_fifth = has(Interval::PERFECT_FIFTH);
+        let flat_fifth = has(Interval::DIMINISHED_FIFTH) && !perfect_fifth;
+        let sharp_fifth = has(Interval::AUGMENTED_FIFTH) && !perfect_fifth;
+        let minor_seventh = has(Interval::MINOR_SEVENTH);
+        let major_seventh = has(Interval::MAJOR_SEVENTH) && !minor_seventh;
+        let seventh = minor_seventh || major_seventh;
+
+        let mut suffix = String::new();
+        let mut alterations = String::new();
+
+        if minor_third && flat_fifth {
+            suffix.push_str(match (minor_seventh, major_seventh) {
+                (true, _) => "ø7",
+                (_, true) => "°(maj7)",
+                _ if has(Interval::DIMINISHED_SEVENTH) => "°7",
+                _ => "°",
+            });
+        } else {
+            let suspension = match (major_third || minor_third, seventh) {
+                (true, _) => "",
+                (false, _) if has(Interval::PERFECT_FOURTH) => "sus4",
+                (false, false) if has(Interval::MAJOR_SECOND) => "sus2",
+                _ => "",
+            };
+            let ninth = has(Interval::MAJOR_SECOND) && suspension != "sus2";
+            let eleventh = has(Interval::PERFECT_FOURTH) && suspension != "sus4";
+            let thirteenth = has(Interval::MAJOR_SIXTH);
+
+            if minor_third {
+                suffix.push('m');
+            } else if sharp_fifth && major_third {
+                suffix.push('+');
+            }
+
+            if seventh {
+                let number = if thirteenth {
+                    13
+                } else if eleventh {
+                    11
+                } else if ninth {
+                    9
+                } else {
+                    7
+                };
+                match (major_seventh, minor_third) {
+                    (true, true) => suffix.push_str(&format!("(maj{})", number)),
+                    (true, false) => suffix.push_str(&format!("maj{}", number)),
+                    (false, _) => suffix.push_str(&number.to_string()),
+                }
+            } else {
+                match (thirteenth, ninth) {
+                    (true, true) => suffix.push_str("6/9"),
+                    (true, false) => suffix.push('6'),
+                    (false, true) => suffix.push_str("add9"),
+                    (false, false) => {}
+                }
+                if eleventh {
+                    suffix.push_str("add11");
+                }
+            }
+            suffix.push_str(suspension);
+
+            if flat_fifth {
+                alterations.push_str(&format!("{}5", FLAT));
+            }
+            if sharp_fifth && !major_third {
+                alterations.push_str(&format!("{}5", SHARP));
+            }
+        }
+
+        for (interval, symbol, number) in [
+            (Interval::MINOR_SECOND, FLAT, 9),
+            (Interval::AUGMENTED_SECOND, SHARP, 9),
+            (Interval::AUGMENTED_FOURTH, SHARP, 11),
+            (Interval::MINOR_SIXTH, FLAT, 13),
+        ] {
+            if has(interval) {
+                alterations.push_str(&format!("{}{}", symbol, number));
+            }
+        }
+        if !alterations.is_empty() {
+            suffix.push_str(&format!("({})", alterations));
+        }
+
+        for omitted in self.omitted_notes() {
+            suffix.push_str(&format!("({})", omitted));
+        }
+        suffix
+    }
+
+    /// Returns the type of seventh chord formed by the third, fifth and seventh
+    ///
+    /// Returns `None` for triads and for sevenths without a matching
+    /// [`SeventhType`], such as suspended or augmented-minor sevenths.
+    pub fn extended_type(&self) -> Option<SeventhType> {
+        let semitones_of = |steps| {
+            self.intervals
+                .iter()
+                .map(|i| i.simple())
+                .find(|i| i.steps() == steps)
+                .map(|i| i.semitones())
+        };
+
+        seventh_type(semitones_of(2)?, semitones_of(4)?, semitones_of(6)?)
+    }
+
+    /// Creates a chord of `count` notes stacked in perfect fourths from the root
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let chord = Chord::quartal(NoteName::new(Letter::C, Accidental::Natural), 4);
+    /// let notes: Vec<String> = chord.notes().iter().map(|n| n.to_string()).collect();
+    /// assert_eq!(notes, ["C", "F", "B♭", "E♭"]);
+    /// ```
+    pub fn quartal(root: NoteName, count: usize) -> Chord {
+        let mut intervals = Vec::with_capacity(count);
+        let mut interval = Interval::PERFECT_UNISON;
+        for _ in 0..count {
+            intervals.push(interval);
+            interval = interval + Interval::PERFECT_FOURTH;
+        }
+
+        Chord { root, intervals }
+    }
+
+    /// Checks whether the chord is a stack of three or more perfect fourths
+    pub fn is_quartal(&self) -> bool {
+        let mut intervals = self.intervals.clone();
+        intervals.sort_by_key(|i| i.semitones());
+
+        intervals.len() >= 3
+            && intervals
+                .windows(2)
+                .all(|pair| pair[1] - pair[0] == Interval::PERFECT_FOURTH)
+    }
+
+    /// Creates the Italian augmented sixth chord of a key (A♭–C–F♯ in C)
+    ///
+    /// All three augmented sixth chords are built on the lowered sixth degree
+    /// and reach up an augmented sixth to the raised fourth degree.
+    pub fn italian_sixth(key: &Key) -> Chord {
+        Chord::augmented_sixth(key, &[Interval::MAJOR_THIRD])
+    }
+
+    /// Creates the French augmented sixth chord of a key (A♭–C–D–F♯ in C)
+    pub fn french_sixth(key: &Key) -> Chord {
+        Chord::augmented_sixth(key, &[Interval::MAJOR_THIRD, Interval::AUGMENTED_FOURTH])
+    }
+
+    /// Creates the German augmented sixth chord of a key (A♭–C–E♭–F♯ in C)
+    pub fn german_sixth(key: &Key) -> Chord {
+        Chord::augmented_sixth(key, &[Interval::MAJOR_THIRD, Interval::PERFECT_FIFTH])
+    }
+
+    fn augmented_sixth(key: &Key, inner: &[Interval]) -> Chord {
+        let mut intervals = vec![Interval::PERFECT_UNISON];
+        intervals.extend_from_slice(inner);
+        intervals.push(Interval::AUGMENTED_SIXTH);
+
+        Chord {
+            root: key.tonic() + Interval::MINOR_SIXTH,
+            intervals,
+        }
+    }
+
+    /// Builds a chord from its notes, detecting the root
+    ///
+    /// Duplicate pitch classes are ignored. Each note is tried as the root, in
+    /// the order given, and the first one whose tones match a known triad or
+    /// seventh chord wins; the chord is then spelled from that root. If nothing
+    /// matches, the first note is taken as the root and the notes keep their
+    /// spelling. An empty slice gives a chord rooted on C with no tones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let notes = [
+    ///     NoteName::new(Letter::E, Accidental::Natural),
+    ///     NoteName::new(Letter::G, Accidental::Natural),
+    ///     NoteName::new(Letter::C, Accidental::Natural),
+    /// ];
+    /// let chord = Chord::from_notes(&notes);
+    /// assert_eq!(chord, Chord::major(NoteName::new(Letter::C, Accidental::Natural)));
+    /// ```
+    pub fn from_notes(notes: &[NoteName]) -> Chord {
+        let mut unique: Vec<NoteName> = Vec::new();
+        for note in notes {
+            if !unique.iter().any(|n| n.is_enharmonic_with(note)) {
+                unique.push(*note);
+            }
+        }
+
+        let Some(&first) = unique.first() else {
+            return Chord {
+                root: NoteName::new(Letter::C, Accidental::Natural),
+                intervals: Vec::new(),
+            };
+        };
+
+        for &root in &unique {
+            let mut classes: Vec<i8> = unique
+                .iter()
+                .map(|n| (n.base_midi_number() - root.base_midi_number()).rem_euclid(12))
+                .collect();
+            classes.sort();
+
+            for template in Chord::templates() {
+                let mut template_classes: Vec<i8> = template
+                    .iter()
+                    .map(|i| i.semitones().rem_euclid(12))
+                    .collect();
+                template_classes.sort();
+
+                if classes == template_classes {
+                    return Chord {
+                        root,
+                        intervals: template.to_vec(),
+                    };
+                }
+            }
+        }
+
+        Chord {
+            root: first,
+            intervals: unique.iter().map(|&n| n - first).collect(),
+        }
+    }
+
+    /// Builds a chord from a roman numeral in a key
+    ///
+    /// The numeral's case gives the triad (upper for major, lower for minor),
+    /// and `°`, `ø` or `+` mark diminished, half-diminished and augmented
+    /// chords. A leading `♭`/`b` or `♯`/`#` lowers or raises the root
+    /// chromatically. Figured-bass figures select a seventh and the
+    /// inversion: `6` and `6/4` for triads, `7`, `6/5`, `4/3` and `4/2` (or
+    /// `2`) for seventh chords; the slash may be left out (`V65`). A `maj`
+    /// before the figure makes the seventh major.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Chord, Key, Invertible, SeventhType};
+    ///
+    /// let c_major = Key::major(NoteName::C);
+    /// let chord = Chord::from_roman_numeral("V6/5", &c_major).unwrap();
+    /// assert_eq!(chord, Chord::seventh(NoteName::G, SeventhType::Dominant).inverted(1));
+    /// assert_eq!(chord.notes()[0], NoteName::B);
+    /// ```
+    pub fn from_roman_numeral(numeral: &str, key: &Key) -> Result<Chord, ParseError> {
+        const NUMERALS: [&str; 7] = ["VII", "VI", "V", "IV", "III", "II", "I"];
+        const DEGREES: [usize; 7] = [7, 6, 5, 4, 3, 2, 1];
+        let invalid = || ParseError::InvalidRomanNumeral(numeral.to_string());
+
+        let (chromatic, rest) = match numeral.chars().next() {
+            Some(c @ ('♭' | 'b')) => (-7, &numeral[c.len_utf8()..]),
+            Some(c @ ('♯' | '#')) => (7, &numeral[c.len_utf8()..]),
+            _ => (0, numeral),
+        };
+
+        let upper = rest.to_ascii_uppercase();
+        let (index, name) = NUMERALS
+            .iter()
+            .enumerate()
+            .find(|(_, name)| upper.starts_with(*name))
+            .ok_or_else(invalid)?;
+        let (written, rest) = rest.split_at(name.len());
+        let minor = written.chars().all(|c| c.is_ascii_lowercase());
+        if !minor && !written.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(invalid());
+        }
+
+        let (mark, rest) = match rest.chars().next() {
+            Some(c @ ('°' | 'o' | 'ø' | '+')) => (Some(c), &rest[c.len_utf8()..]),
+            _ => (None, rest),
+        };
+        let (major_seventh, figures) = match rest.strip_prefix("maj") {
+            Some(figures) => (true, figures),
+            None => (false, rest),
+        };
+        let (seventh, inversion) = match figures {
+            "" | "5/3" | "53" => (major_seventh || mark == Some('ø'), 0),
+            "6" | "6/3" | "63" => (false, 1),
+            "6/4" | "64" => (false, 2),
+            "7" => (true, 0),
+            "6/5" | "65" => (true, 1),
+            "4/3" | "43" => (true, 2),
+            "4/2" | "42" | "2" => (true, 3),
+            _ => return Err(invalid()),
+        };
+        if major_seventh && !seventh {
+            return Err(invalid());
+        }
+
+        let scale_root = key.scale().notes()[DEGREES[index] - 1];
+        let root = NoteName::from_fifths((scale_root.fifths() + chromatic).into());
+
+        let chord = match (mark, minor, seventh) {
+            (Some('ø'), _, true) => Chord::seventh(root, SeventhType::HalfDiminished),
+            (Some('ø'), _, false) => return Err(invalid()),
+            (Some('°' | 'o'), _, true) => Chord::seventh(root, SeventhType::Diminished),
+            (Some('°' | 'o'), _, false) => Chord::diminished(root),
+            (Some('+'), _, true) if major_seventh => {
+                Chord::seventh(root, SeventhType::AugmentedMajor)
+            }
+            (Some('+'), _, true) => Chord::augmented(root).add_interval(Interval::MINOR_SEVENTH),
+            (Some(_), _, false) => Chord::augmented(root),
+            (_, true, true) if major_seventh => Chord::seventh(root, SeventhType::MinorMajor),
+            (_, true, true) => Chord::seventh(root, SeventhType::Minor),
+            (_, true, false) => Chord::minor(root),
+            (_, false, true) if major_seventh => Chord::seventh(root, SeventhType::Major),
+            (_, false, true) => Chord::seventh(root, SeventhType::Dominant),
+            (_, false, false) => Chord::major(root),
+        };
+        Ok(chord.inverted(inversion))
+    }
+
+    /// Builds a chord from MIDI note numbers, detecting the root
+    ///
+    /// Notes are spelled with [`Pitch::from_midi`] and handed to
+    /// [`Chord::from_notes`], so octave doublings are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let chord = Chord::from_midi(&[60, 64, 67]);
+    /// assert_eq!(chord, Chord::major(NoteName::new(Letter::C, Accidental::Natural)));
+    /// ```
+    pub fn from_midi(notes: &[u8]) -> Chord {
+        let names: Vec<NoteName> = notes.iter().map(|&n| Pitch::from_midi(n).name).collect();
+        Chord::from_notes(&names)
+    }
+
+    /// The chord shapes recognized by [`Chord::from_notes`]
+    fn templates() -> impl Iterator<Item = &'static [Interval]> {
+        const TRIADS: [ChordQuality; 6] = [
+            ChordQuality::Major,
+            ChordQuality::Minor,
+            ChordQuality::Diminished,
+            ChordQuality::Augmented,
+            ChordQuality::Sus4,
+            ChordQuality::Sus2,
+        ];
+        const SEVENTHS: [SeventhType; 5] = [
+            SeventhType::Dominant,
+            SeventhType::Major,
+            SeventhType::Minor,
+            SeventhType::HalfDiminished,
+            SeventhType::Diminished,
+        ];
+
+        TRIADS
+            .iter()
+            .map(|q| q.intervals())
+            .chain(SEVENTHS.iter().map(|s| s.intervals()))
+    }
+
+    pub fn root(&self) -> NoteName {
+        self.root
+    }
+
+    /// Returns the quality of the chord's underlying triad
+    ///
+    /// Tones are compared as simple intervals, so inversions, which move
+    /// tones up an octave, keep their quality. Chords without a third are
+    /// reported as suspended when they have a fourth or second, and as major
+    /// otherwise.
+    pub fn quality(&self) -> ChordQuality {
+        let has = |interval| self.intervals.iter().any(|i| i.simple() == interval);
+
+        if has(Interval::MAJOR_THIRD) {
+            if has(Interval::AUGMENTED_FIFTH) && !has(Interval::PERFECT_FIFTH) {
+                ChordQuality::Augmented
+            } else {
+                ChordQuality::Major
+            }
+        } else if has(Interval::MINOR_THIRD) {
+            if has(Interval::DIMINISHED_FIFTH) && !has(Interval::PERFECT_FIFTH) {
+                ChordQuality::Diminished
+            } else {
+                ChordQuality::Minor
+            }
+        } else if has(Interval::PERFECT_FOURTH) {
+            ChordQuality::Sus4
+        } else if has(Interval::MAJOR_SECOND) {
+            ChordQuality::Sus2
+        } else {
+            ChordQuality::Major
+        }
+    }
+
+    /// Returns the note names of the chord tones, starting from the root
+    pub fn notes(&self) -> Vec<NoteName> {
+        self.intervals.iter().map(|&i| self.root + i).collect()
+    }
+
+    /// Checks whether the chord contains a note, matching enharmonically
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    /// assert!(c_major.contains_note(&NoteName::new(Letter::E, Accidental::Natural)));
+    /// assert!(c_major.contains_note(&NoteName::new(Letter::F, Accidental::Flat)));
+    /// assert!(!c_major.contains_note(&NoteName::new(Letter::F, Accidental::Natural)));
+    /// ```
+    pub fn contains_note(&self, note: &NoteName) -> bool {
+        self.notes().iter().any(|n| n.is_enharmonic_with(note))
+    }
+
+    /// Returns the notes two chords share, matching by pitch class
+    ///
+    /// Notes are returned in this chord's spelling and order.
+    pub fn common_tones(&self, other: &Chord) -> Vec<NoteName> {
+        self.notes()
+            .into_iter()
+            .filter(|n| other.contains_note(n))
+            .collect()
+    }
+
+    /// Returns the notes that change when moving from this chord to `next`
+    ///
+    /// The first vector holds the notes left behind, the second the notes
+    /// that are new in `next`; both are matched by pitch class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let c = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    /// let a_minor = Chord::minor(NoteName::new(Letter::A, Accidental::Natural));
+    /// let (removed, added) = c.changed_tones(&a_minor);
+    /// assert_eq!(removed, vec![NoteName::new(Letter::G, Accidental::Natural)]);
+    /// assert_eq!(added, vec![NoteName::new(Letter::A, Accidental::Natural)]);
+    /// ```
+    pub fn changed_tones(&self, next: &Chord) -> (Vec<NoteName>, Vec<NoteName>) {
+        let removed = self
+            .notes()
+            .into_iter()
+            .filter(|n| !next.contains_note(n))
+            .collect();
+        let added = next
+            .notes()
+            .into_iter()
+            .filter(|n| !self.contains_note(n))
+            .collect();
+        (removed, added)
+    }
+
+    /// Suggests scales on the chord's root that fit over the chord
+    ///
+    /// Every chord tone must be in the scale, except that an unaltered
+    /// perfect fifth may be missing (so the altered scale fits a dominant
+    /// seventh). Scales containing more of the chord tones come first; ties
+    /// keep the order of [`scales::REGISTRY`].
+    ///
+    /// Every registry definition is a candidate, so registering a new scale
+    /// can add suggestions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Scale, scales, SeventhType};
+    ///
+    /// let c = NoteName::new(Letter::C, Accidental::Natural);
+    /// let suggestions = Chord::seventh(c, SeventhType::Dominant).matching_scales();
+    /// assert_eq!(suggestions[0], Scale::new(c, scales::MIXOLYDIAN));
+    /// assert!(suggestions.contains(&Scale::new(c, scales::ALTERED)));
+    /// ```
+    pub fn matching_scales(&self) -> Vec<Scale> {
+        let mut matches: Vec<(usize, Scale)> = scales::REGISTRY
+            .iter()
+            .filter_map(|definition| {
+                let bitmask = definition.bitmask();
+                let contains = |i: &Interval| bitmask.contains(i.semitones().rem_euclid(12) as u8);
+
+                let fits = self
+                    .intervals
+                    .iter()
+                    .all(|i| *i == Interval::PERFECT_FIFTH || contains(i));
+                let core_tones = self.intervals.iter().filter(|i| contains(i)).count();
+                fits.then(|| (core_tones, Scale::new(self.root, definition.clone())))
+            })
+            .collect();
+
+        matches.sort_by_key(|(core_tones, _)| std::cmp::Reverse(*core_tones));
+        matches.into_iter().map(|(_, scale)| scale).collect()
+    }
+
+    /// Returns a copy of the chord with an interval added above the root
+    ///
+    /// Intervals stay sorted from the root upwards; adding an interval the
+    /// chord already has returns an identical chord.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Interval, SeventhType};
+    ///
+    /// let c = NoteName::new(Letter::C, Accidental::Natural);
+    /// let c_maj7 = Chord::major(c).add_interval(Interval::MAJOR_SEVENTH);
+    /// assert_eq!(c_maj7, Chord::seventh(c, SeventhType::Major));
+    /// ```
+    pub fn add_interval(&self, interval: Interval) -> Chord {
+        let mut intervals = self.intervals.clone();
+        if !intervals.contains(&interval) {
+            intervals.push(interval);
+            intervals.sort_by_key(|i| (i.semitones(), i.steps()));
+        }
+
+        Chord {
+            root: self.root,
+            intervals,
+        }
+    }
+
+    /// Returns a copy of the chord without the given interval
+    ///
+    /// Intervals are matched exactly, so removing a minor seventh leaves a
+    /// major seventh in place.
+    pub fn remove_interval(&self, interval: Interval) -> Chord {
+        Chord {
+            root: self.root,
+            intervals: self
+                .intervals
+                .iter()
+                .copied()
+                .filter(|&i| i != interval)
+                .collect(),
+        }
+    }
+
+    /// Returns a copy of the chord with an extension or alteration applied
+    ///
+    /// Sevenths, alterations and suspensions replace the tone they change (a
+    /// sharp fifth replaces the perfect fifth); omissions remove tones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, ChordExtension, NinthType, SeventhType};
+    ///
+    /// let c7 = Chord::seventh(NoteName::new(Letter::C, Accidental::Natural), SeventhType::Dominant);
+    /// let c9 = c7.with_extension(ChordExtension::Ninth(NinthType::Natural));
+    /// assert_eq!(c9.notes()[4], NoteName::new(Letter::D, Accidental::Natural));
+    /// ```
+    pub fn with_extension(&self, extension: ChordExtension) -> Chord {
+        let (replaced_steps, add) = match extension {
+            ChordExtension::Seventh(seventh) => (Some(6), seventh.intervals().last().copied()),
+            ChordExtension::Ninth(ninth) => (
+                Some(8),
+                Some(match ninth {
+                    NinthType::Natural => Interval::MAJOR_NINTH,
+                    NinthType::Flat => Interval::MINOR_NINTH,
+                    NinthType::Sharp => Interval::AUGMENTED_NINTH,
+                }),
+            ),
+            ChordExtension::Eleventh(eleventh) => (
+                Some(10),
+                Some(match eleventh {
+                    EleventhType::Natural => Interval::PERFECT_ELEVENTH,
+                    EleventhType::Sharp => Interval::AUGMENTED_ELEVENTH,
+                }),
+            ),
+            ChordExtension::Thirteenth(thirteenth) => (
+                Some(12),
+                Some(match thirteenth {
+                    ThirteenthType::Natural => Interval::MAJOR_THIRTEENTH,
+                    ThirteenthType::Flat => Interval::MINOR_THIRTEENTH,
+                }),
+            ),
+            ChordExtension::Add(added) => (
+                None,
+                Some(match added {
+                    AddedNote::Add2 => Interval::MAJOR_SECOND,
+                    AddedNote::Add4 => Interval::PERFECT_FOURTH,
+                    AddedNote::Add6 => Interval::MAJOR_SIXTH,
+                    AddedNote::AddFlat6 => Interval::MINOR_SIXTH,
+                }),
+            ),
+            ChordExtension::Sus(suspended) => (
+                Some(2),
+                Some(match suspended {
+                    SuspendedType::Sus2 => Interval::MAJOR_SECOND,
+                    SuspendedType::Sus4 => Interval::PERFECT_FOURTH,
+                }),
+            ),
+            ChordExtension::AlteredFifth(fifth) => (
+                Some(4),
+                Some(match fifth {
+                    AlteredFifthType::Flat => Interval::DIMINISHED_FIFTH,
+                    AlteredFifthType::Sharp => Interval::AUGMENTED_FIFTH,
+                }),
+            ),
+            ChordExtension::AlteredNinth(ninth) => (
+                Some(8),
+                Some(match ninth {
+                    AlteredNinthType::Flat => Interval::MINOR_NINTH,
+                    AlteredNinthType::Sharp => Interval::AUGMENTED_NINTH,
+                }),
+            ),
+            ChordExtension::Omit(OmittedNote::No3) => (Some(2), None),
+            ChordExtension::Omit(OmittedNote::No5) => (Some(4), None),
+        };
+
+        let mut chord = self.clone();
+        if let Some(steps) = replaced_steps {
+            chord.intervals.retain(|i| i.steps() != steps);
+        }
+        match add {
+            Some(interval) => chord.add_interval(interval),
+            None => chord,
+        }
+    }
+
+    /// Finds the major and minor triads formed by the chord's upper extensions
+    ///
+    /// A triad counts as an upper structure when all of its notes are in the
+    /// chord and at least two of them are extensions (ninths, elevenths or
+    /// thirteenths). A C13♯11 chord has a D major triad (9, ♯11, 13) on top.
+    pub fn upper_structures(&self) -> Vec<Chord> {
+        let extensions: Vec<NoteName> = self
+            .intervals
+            .iter()
+            .filter(|i| i.steps() > 6)
+            .map(|&i| self.root + i)
+            .collect();
+
+        let mut structures: Vec<Chord> = Vec::new();
+        for root in self.notes() {
+            for triad in [Chord::major(root), Chord::minor(root)] {
+                let notes = triad.notes();
+                let tension_count = notes
+                    .iter()
+                    .filter(|n| extensions.iter().any(|e| e.is_enharmonic_with(n)))
+                    .count();
+
+                if tension_count >= 2
+                    && notes.iter().all(|n| self.contains_note(n))
+                    && !structures.contains(&triad)
+                {
+                    structures.push(triad);
+                }
+            }
+        }
+        structures
+    }
+
+    /// Respells the chord enharmonically so it reads well in a key
+    ///
+    /// Every enharmonic spelling of the root is tried, keeping the chord's
+    /// intervals, and the one whose notes best follow the key signature wins.
+    /// Notes that match the signature cost nothing; other notes cost more the
+    /// more accidentals they need, and a bit extra if the accidental goes
+    /// against the key (a sharp in a flat key). Ties keep the current spelling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Key};
+    ///
+    /// let d_sharp_major = Chord::major(NoteName::new(Letter::D, Accidental::Sharp));
+    /// let key = Key::major(NoteName::new(Letter::B, Accidental::Flat));
+    /// let respelled = d_sharp_major.respell_for_key(&key);
+    /// assert_eq!(respelled, Chord::major(NoteName::new(Letter::E, Accidental::Flat)));
+    /// ```
+    pub fn respell_for_key(&self, key: &Key) -> Chord {
+        let signature = key.accidentals();
+        let direction = key.signature_fifths().signum();
+
+        let score = |note: NoteName| -> u32 {
+            let expected = signature
+                .iter()
+                .find(|n| n.letter == note.letter)
+                .map_or(Accidental::Natural, |n| n.accidental);
+            if note.accidental == expected {
+                return 0;
+            }
+
+            let offset = note.accidental.semitone_offset();
+            let mut cost = 2 * offset.unsigned_abs() as u32 + 1;
+            if offset != 0 && direction != 0 && offset.signum() != direction {
+                cost += 1;
+            }
+            cost
+        };
+
+        let mut best = self.clone();
+        let mut best_score: u32 = self.notes().into_iter().map(score).sum();
+
+        for accidental in Accidental::all() {
+            let Some(root) = self.root.respell_as(accidental) else {
+                continue;
+            };
+            let candidate = Chord {
+                root,
+                intervals: self.intervals.clone(),
+            };
+            let candidate_score = candidate.notes().into_iter().map(score).sum();
+            if candidate_score < best_score {
+                best = candidate;
+                best_score = candidate_score;
+            }
+        }
+
+        best
+    }
+
+    /// Realizes the chord as pitches, with the root in the given octave
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let a_minor = Chord::minor(NoteName::new(Letter::A, Accidental::Natural));
+    /// let pitches: Vec<String> = a_minor.voice(3).iter().map(|p| p.to_string()).collect();
+    /// assert_eq!(pitches, ["A3", "C4", "E4"]);
+    /// ```
+    pub fn voice(&self, octave: i8) -> Vec<Pitch> {
+        let root = Pitch::new(self.root, octave);
+        self.intervals
+            .iter()
+            .map(|&i| root.transpose_by_interval(i))
+            .collect()
+    }
+
+    /// Realizes the chord as pitches in voicing order, with the root in octave 4
+    ///
+    /// Unlike [`Chord::notes`], this keeps the octave of each tone, so an
+    /// inversion has its new bass lowest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{pitch, Chord, Invertible, NoteName};
+    ///
+    /// let first_inversion = Chord::major(NoteName::C).inverted(1);
+    /// assert_eq!(first_inversion.voiced_notes(), [pitch!("E4"), pitch!("G4"), pitch!("C5")]);
+    /// ```
+    pub fn voiced_notes(&self) -> Vec<Pitch> {
+        self.voice(DEFAULT_OCTAVE)
+    }
+
+    /// Returns the shell voicing: root, third and seventh, with the root in the given octave
+    ///
+    /// The fifth and any extensions are left out and the three tones are
+    /// placed in close position above the root. Suspended sevenths use the
+    /// fourth or second in place of the third. Chords without a seventh are
+    /// voiced in full, as by [`Chord::voice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{pitch, Chord, NoteName, SeventhType};
+    ///
+    /// let shell = Chord::seventh(NoteName::C, SeventhType::Major).shell(4);
+    /// assert_eq!(shell, [pitch!("C4"), pitch!("E4"), pitch!("B4")]);
+    /// ```
+    pub fn shell(&self, octave: i8) -> Vec<Pitch> {
+        let Some(seventh) = self.simple_with_steps(6) else {
+            return self.voice(octave);
+        };
+        let third = self.voicing_third();
+
+        let root = Pitch::new(self.root, octave);
+        std::iter::once(Interval::PERFECT_UNISON)
+            .chain(third)
+            .chain(std::iter::once(seventh))
+            .map(|i| root.transpose_by_interval(i))
+            .collect()
+    }
+
+    /// Returns a rootless voicing for when a bass player covers the root
+    ///
+    /// Tones are chosen and stacked from the bottom up as:
+    ///
+    /// 1. the third, or the fourth or second of a suspended chord;
+    /// 2. the fifth, or the thirteenth in its place when the chord has one;
+    /// 3. the seventh;
+    /// 4. the ninth, if the chord has one, an octave above the root.
+    ///
+    /// The root and any eleventh are left out, and the lower tones sit in the
+    /// octave above the root in `octave`. Chords without a seventh are voiced
+    /// in full, as by [`Chord::voice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{pitch, Chord, Interval, NoteName, SeventhType};
+    ///
+    /// let c9 = Chord::seventh(NoteName::C, SeventhType::Dominant).add_interval(Interval::MAJOR_NINTH);
+    /// assert_eq!(c9.rootless(4), [pitch!("E4"), pitch!("G4"), pitch!("Bb4"), pitch!("D5")]);
+    /// ```
+    pub fn rootless(&self, octave: i8) -> Vec<Pitch> {
+        let Some(seventh) = self.simple_with_steps(6) else {
+            return self.voice(octave);
+        };
+        let third = self.voicing_third();
+        let fifth = self
+            .simple_with_steps(5)
+            .or_else(|| self.simple_with_steps(4));
+        let ninth = self
+            .simple_with_steps(1)
+            .filter(|_| third != self.simple_with_steps(1))
+            .map(|i| i + Interval::PERFECT_OCTAVE);
+
+        let root = Pitch::new(self.root, octave);
+        [third, fifth, Some(seventh), ninth]
+            .into_iter()
+            .flatten()
+            .map(|i| root.transpose_by_interval(i))
+            .collect()
+    }
+
+    /// Returns the first chord tone, reduced to within an octave, that spans the given letter steps
+    fn simple_with_steps(&self, steps: i8) -> Option<Interval> {
+        self.intervals
+            .iter()
+            .map(|i| i.simple())
+            .find(|i| i.steps() == steps)
+    }
+
+    /// Returns the third for shell and rootless voicings, or the fourth or second of a suspended chord
+    fn voicing_third(&self) -> Option<Interval> {
+        self.simple_with_steps(2)
+            .or_else(|| self.simple_with_steps(3))
+            .or_else(|| self.simple_with_steps(1))
+    }
+
+    /// Returns the MIDI note numbers of the chord voiced from the given octave
+    ///
+    /// The numbers are those of [`Pitch::midi_number`], so middle C (C4) is
+    /// 60 and [`Pitch::from_midi`] gives back the voiced pitches. If the voicing leaves the MIDI range, the whole chord is moved by
+    /// octaves until it fits, so the tones stay in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    /// assert_eq!(c_major.to_midi(4), vec![60, 64, 67]);
+    /// ```
+    pub fn to_midi(&self, octave: i8) -> Vec<u8> {
+        let numbers: Vec<i16> = self.voice(octave).iter().map(Pitch::midi_number).collect();
+        let (Some(&low), Some(&high)) = (numbers.iter().min(), numbers.iter().max()) else {
+            return Vec::new();
+        };
+
+        let shift = if high > 127 {
+            -((high - 127 + 11) / 12) * 12
+        } else if low < 0 {
+            (-low + 11) / 12 * 12
+        } else {
+            0
+        };
+        // A chord wider than the MIDI range can't be moved as a whole, so the
+        // tones that still don't fit are clamped individually
+        numbers
+            .iter()
+            .map(|&n| {
+                let mut number = n + shift;
+                while number > 127 {
+                    number -= 12;
+                }
+                while number < 0 {
+                    number += 12;
+                }
+                number as u8
+            })
+            .collect()
+    }
+
+    /// Renders the chord as an ABC chord group rooted on middle C's octave
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let g_major = Chord::major(NoteName::new(Letter::G, Accidental::Natural));
+    /// assert_eq!(g_major.to_abc(), "[GBd]");
+    /// ```
+    pub fn to_abc(&self) -> String {
+        let notes: String = self.voice(4).iter().map(|p| p.to_abc()).collect();
+        format!("[{}]", notes)
+    }
+
+    /// Renders the chord as a LilyPond chord rooted on middle C's octave
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord};
+    ///
+    /// let d_minor = Chord::minor(NoteName::new(Letter::D, Accidental::Natural));
+    /// assert_eq!(d_minor.to_lilypond(), "<d' f' a'>");
+    /// ```
+    pub fn to_lilypond(&self) -> String {
+        let notes: Vec<String> = self.voice(4).iter().map(|p| p.to_lilypond()).collect();
+        format!("<{}>", notes.join(" "))
+    }
+}
+
+/// Collects note names into a chord, detecting the root with [`Chord::from_notes`]
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Chord};
+///
+/// let notes = vec![
+///     NoteName::new(Letter::F, Accidental::Natural),
+///     NoteName::new(Letter::A, Accidental::Flat),
+///     NoteName::new(Letter::C, Accidental::Natural),
+/// ];
+/// let chord: Chord = notes.into_iter().collect();
+/// assert_eq!(chord, Chord::minor(NoteName::new(Letter::F, Accidental::Natural)));
+/// ```
+impl FromIterator<NoteName> for Chord {
+    fn from_iter<I: IntoIterator<Item = NoteName>>(iter: I) -> Self {
+        let notes: Vec<NoteName> = iter.into_iter().collect();
+        Chord::from_notes(&notes)
+    }
+}
+
+impl IntoIterator for Chord {
+    type Item = NoteName;
+    type IntoIter = std::vec::IntoIter<NoteName>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.notes().into_iter()
+    }
+}
+
+impl IntoIterator for &Chord {
+    type Item = NoteName;
+    type IntoIter = std::vec::IntoIter<NoteName>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.notes().into_iter()
+    }
+}
+
+/// Rotating the tones of a chord or scale
+///
+/// For chords this gives the inversions (the lowest tone moves up an
+/// octave), for scales the modes (the scale is re-rooted on a later degree).
+pub trait Invertible: Sized {
+    /// Returns the `n`th inversion or rotation
+    ///
+    /// `n` is taken modulo the number of tones, so rotating a triad three
+    /// times gives root position again.
+    fn inverted(&self, n: usize) -> Self;
+
+    /// Returns the next inversion or rotation
+    fn invert(&self) -> Self {
+        self.inverted(1)
+    }
+}
+
+/// Inverts a chord by moving its lowest tones up an octave
+///
+/// The root stays the same; only the order of the intervals changes, so the
+/// first note of [`Chord::notes`] is the bass.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Chord, Invertible};
+///
+/// let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+/// let first = c_major.inverted(1);
+/// assert_eq!(first.notes()[0], NoteName::new(Letter::E, Accidental::Natural));
+/// assert_eq!(first.root(), c_major.root());
+/// ```
+impl Invertible for Chord {
+    fn inverted(&self, n: usize) -> Self {
+        let mut intervals = self.intervals.clone();
+        if intervals.is_empty() {
+            return self.clone();
+        }
+
+        for _ in 0..n % intervals.len() {
+            let top = *intervals.last().expect("chord has tones");
+            let mut bass = intervals.remove(0);
+            while bass.semitones() <= top.semitones() {
+                bass = bass + Interval::PERFECT_OCTAVE;
+            }
+            intervals.push(bass);
+        }
+
+        // Keep the bass within the octave above the root
+        let octaves = intervals[0].semitones().div_euclid(12);
+        let shift = Interval::new(0, octaves);
+        Chord {
+            root: self.root,
+            intervals: intervals.into_iter().map(|i| i - shift).collect(),
+        }
+    }
+}
+
+impl Index<usize> for Scale {
+    type Output = NoteName;
+
+    /// Returns the note on a 1-based scale degree, so `scale[1]` is the tonic
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is 0 or larger than the number of notes in the
+    /// scale; use [`Scale::get`] to avoid that.
+    fn index(&self, degree: usize) -> &NoteName {
+        let notes = self.notes_cached();
+        assert!(
+            (1..=notes.len()).contains(&degree),
+            "scale degree {} is out of range",
+            degree
+        );
+        &notes[degree - 1]
+    }
+}
+
+/// Rotates a scale to one of its modes, re-rooting it on a later degree
+///
+/// The new definition is looked up in [`scales::REGISTRY`], preferring an
+/// entry with exactly the rotated intervals and falling back to one with the
+/// same pitch classes. Every built-in definition has its modes registered;
+/// modes of custom definitions that aren't found get a custom definition
+/// named after the original, such as "Triad mode 2".
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Scale, Invertible, scales};
+///
+/// let c_ionian = Scale::new(NoteName::new(Letter::C, Accidental::Natural), scales::IONIAN);
+/// let d_dorian = Scale::new(NoteName::new(Letter::D, Accidental::Natural), scales::DORIAN);
+/// assert_eq!(c_ionian.inverted(1), d_dorian);
+/// ```
+impl Invertible for Scale {
+    fn inverted(&self, n: usize) -> Self {
+        let notes = self.notes_cached();
+        if notes.is_empty() {
+            return self.clone();
+        }
+
+        let tonic = notes[n % notes.len()];
+        let intervals: Vec<Interval> = (0..notes.len())
+            .map(|i| notes[(n + i) % notes.len()] - tonic)
+            .collect();
+        let bitmask = scales::ScaleBitmask::from_intervals(&intervals);
+
+        let definition = scales::REGISTRY
+            .iter()
+            .find(|d| d.intervals() == intervals.as_slice())
+            .or_else(|| scales::REGISTRY.iter().find(|d| d.bitmask() == bitmask))
+            .cloned()
+            .unwrap_or_else(|| {
+                let name = format!("{} mode {}", self.definition.name(), n % notes.len() + 1);
+                ScaleDefinition::custom(name, intervals)
+            });
+        Scale::new(tonic, definition)
+    }
+}
+
+/// Something made of intervals above a root, such as a chord or a scale
+pub trait HasIntervals {
+    /// The tones as intervals above the root
+    fn intervals(&self) -> &[Interval];
+
+    /// Pairs each interval with its 1-based position among the tones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, HasIntervals, Interval, SeventhType};
+    ///
+    /// let g7 = Chord::seventh(NoteName::new(Letter::G, Accidental::Natural), SeventhType::Dominant);
+    /// let seventh = g7.degree_intervals().find(|(_, i)| *i == Interval::MINOR_SEVENTH);
+    /// assert_eq!(seventh, Some((4, Interval::MINOR_SEVENTH)));
+    /// ```
+    fn degree_intervals(&self) -> impl Iterator<Item = (usize, Interval)> + '_ {
+        self.intervals()
+            .iter()
+            .enumerate()
+            .map(|(i, &interval)| (i + 1, interval))
+    }
+}
+
+impl HasIntervals for Chord {
+    fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+}
+
+impl HasIntervals for Scale {
+    fn intervals(&self) -> &[Interval] {
+        self.definition.intervals()
+    }
+}
+
+/// A set of tones above a root that chords can be built from
+///
+/// Implemented by [`Scale`] and [`Chord`], so the same search finds the
+/// triads of a scale or the triads hidden inside an extended chord.
+pub trait ChordLike: HasIntervals {
+    fn root(&self) -> NoteName;
+
+    /// Finds every triad that can be stacked from the tones
+    ///
+    /// Each tone is tried as a root, combined with a third (minor or major)
+    /// and a fifth (diminished, perfect or augmented) that are also among the
+    /// tones. Chords are ordered by root, then by third, then by fifth, in
+    /// the order the tones appear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, ChordLike, Scale, ScaleType};
+    ///
+    /// let c_major = Scale::new(NoteName::new(Letter::C, Accidental::Natural), ScaleType::Major);
+    /// let triads = c_major.triads();
+    /// assert_eq!(triads.len(), 7);
+    /// assert_eq!(triads[1], Chord::minor(NoteName::new(Letter::D, Accidental::Natural)));
+    /// ```
+    fn triads(&self) -> Vec<Chord> {
+        let notes = tone_names(self);
+        let by_pitch_class = pitch_class_index(&notes);
+        let above = |root, semitones: &[i8], steps| {
+            tones_above(&notes, &by_pitch_class, root, semitones, steps)
+        };
+
+        let mut triads = Vec::new();
+        for root in 0..notes.len() {
+            let thirds = above(root, &THIRDS, 2);
+            if thirds.is_empty() {
+                continue;
+            }
+            let fifths = above(root, &FIFTHS, 4);
+
+            for &third in &thirds {
+                for &fifth in &fifths {
+                    let third = (notes[third] - notes[root]).semitones();
+                    let fifth = (notes[fifth] - notes[root]).semitones();
+                    if let Some(quality) = triad_quality(third, fifth) {
+                        triads.push(Chord::new(notes[root], quality));
+                    }
+                }
+            }
+        }
+        triads
+    }
+
+    /// Finds every seventh chord that can be stacked from the tones
+    ///
+    /// Works like [`ChordLike::triads`], with a seventh (diminished, minor or
+    /// major) on top.
+    fn sevenths(&self) -> Vec<Chord> {
+        let notes = tone_names(self);
+        let by_pitch_class = pitch_class_index(&notes);
+        let above = |root, semitones: &[i8], steps| {
+            tones_above(&notes, &by_pitch_class, root, semitones, steps)
+        };
+
+        let mut sevenths = Vec::new();
+        for root in 0..notes.len() {
+            let thirds = above(root, &THIRDS, 2);
+            let fifths = above(root, &FIFTHS, 4);
+            let seventh_tones = above(root, &SEVENTHS, 6);
+            if thirds.is_empty() || fifths.is_empty() || seventh_tones.is_empty() {
+                continue;
+            }
+
+            let size = |i: usize| (notes[i] - notes[root]).semitones();
+            for &third in &thirds {
+                for &fifth in &fifths {
+                    for &seventh in &seventh_tones {
+                        if let Some(seventh_type) =
+                            seventh_type(size(third), size(fifth), size(seventh))
+                        {
+                            sevenths.push(Chord::seventh(notes[root], seventh_type));
+                        }
+                    }
+                }
+            }
+        }
+        sevenths
+    }
+
+    /// Finds every ninth chord: a seventh chord from [`ChordLike::sevenths`]
+    /// with one of the tones a third above its seventh added as the ninth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, ChordLike, Scale, ScaleType};
+    ///
+    /// let c_major = Scale::new(NoteName::new(Letter::C, Accidental::Natural), ScaleType::Major);
+    /// let g9 = &c_major.ninths()[4];
+    /// assert_eq!(g9.notes()[4], NoteName::new(Letter::A, Accidental::Natural));
+    /// ```
+    fn ninths(&self) -> Vec<Chord> {
+        stack_third(self, self.sevenths())
+    }
+
+    /// Finds every eleventh chord, stacking a third on top of each ninth chord
+    fn elevenths(&self) -> Vec<Chord> {
+        stack_third(self, self.ninths())
+    }
+
+    /// Finds every thirteenth chord, stacking a third on top of each eleventh
+    /// chord
+    fn thirteenths(&self) -> Vec<Chord> {
+        stack_third(self, self.elevenths())
+    }
+}
+
+/// Extends each chord with every tone that lies a third above its top tone
+fn stack_third<C: ChordLike + ?Sized>(chord_like: &C, chords: Vec<Chord>) -> Vec<Chord> {
+    let notes = tone_names(chord_like);
+    let by_pitch_class = pitch_class_index(&notes);
+
+    let mut stacked = Vec::new();
+    for chord in chords {
+        let Some(&top) = chord.intervals.last() else {
+            continue;
+        };
+        let Some(top_index) = notes.iter().position(|&n| n == chord.root + top) else {
+            continue;
+        };
+
+        for next in tones_above(&notes, &by_pitch_class, top_index, &THIRDS, 2) {
+            let interval = top + (notes[next] - notes[top_index]);
+            stacked.push(chord.add_interval(interval));
+        }
+    }
+    stacked
+}
+
+impl ChordLike for Chord {
+    fn root(&self) -> NoteName {
+        self.root
+    }
+}
+
+impl ChordLike for Scale {
+    fn root(&self) -> NoteName {
+        self.tonic
+    }
+}
+
+fn tone_names<C: ChordLike + ?Sized>(chord_like: &C) -> Vec<NoteName> {
+    let root = chord_like.root();
+    chord_like.intervals().iter().map(|&i| root + i).collect()
+}
+
+/// Semitone sizes of the thirds, fifths and sevenths used in triads and
+/// seventh chords
+const THIRDS: [i8; 2] = [3, 4];
+const FIFTHS: [i8; 3] = [6, 7, 8];
+const SEVENTHS: [i8; 3] = [9, 10, 11];
+
+/// Indices of the tones that lie one of `semitones` above `root` and are
+/// spelled as the generic interval `steps` (2 for a third, 4 for a fifth),
+/// in tone order
+fn tones_above(
+    notes: &[NoteName],
+    by_pitch_class: &[Vec<usize>; 12],
+    root: usize,
+    semitones: &[i8],
+    steps: i8,
+) -> Vec<usize> {
+    let mut found: Vec<usize> = semitones
+        .iter()
+        .flat_map(|s| {
+            let pitch_class = (notes[root].base_midi_number() + s).rem_euclid(12);
+            by_pitch_class[pitch_class as usize].iter().copied()
+        })
+        .filter(|&i| (notes[i] - notes[root]).steps() == steps)
+        .collect();
+    found.sort_unstable();
+    found
+}
+
+/// Groups tone indices by pitch class (0 for C through 11 for B)
+fn pitch_class_index(notes: &[NoteName]) -> [Vec<usize>; 12] {
+    let mut index: [Vec<usize>; 12] = Default::default();
+    for (i, note) in notes.iter().enumerate() {
+        index[note.pitch_class() as usize].push(i);
+    }
+    index
+}
+
+/// Builds the triad on `root` if the other two notes are its third and fifth
+fn triad_from(root: NoteName, a: NoteName, b: NoteName) -> Option<Chord> {
+    let (mut third, mut fifth) = ((a - root).simple(), (b - root).simple());
+    if third.steps() > fifth.steps() {
+        std::mem::swap(&mut third, &mut fifth);
+    }
+    if third.steps() != 2 || fifth.steps() != 4 {
+        return None;
+    }
+    triad_quality(third.semitones(), fifth.semitones()).map(|quality| Chord::new(root, quality))
+}
+
+fn triad_quality(third: i8, fifth: i8) -> Option<ChordQuality> {
+    match (third, fifth) {
+        (4, 7) => Some(ChordQuality::Major),
+        (3, 7) => Some(ChordQuality::Minor),
+        (3, 6) => Some(ChordQuality::Diminished),
+        (4, 8) => Some(ChordQuality::Augmented),
+        _ => None,
+    }
+}
+
+fn seventh_type(third: i8, fifth: i8, seventh: i8) -> Option<SeventhType> {
+    match (third, fifth, seventh) {
+        (4, 7, 10) => Some(SeventhType::Dominant),
+        (4, 7, 11) => Some(SeventhType::Major),
+        (3, 7, 10) => Some(SeventhType::Minor),
+        (3, 6, 10) => Some(SeventhType::HalfDiminished),
+        (3, 6, 9) => Some(SeventhType::Diminished),
+        (3, 7, 11) => Some(SeventhType::MinorMajor),
+        (4, 8, 11) => Some(SeventhType::AugmentedMajor),
+        _ => None,
+    }
+}
+
+/// A scale with a tonic and a scale definition
+#[derive(Clone)]
+pub struct Scale {
+    tonic: NoteName,
+    definition: ScaleDefinition,
+    /// Spelled notes, filled on first use by [`Scale::notes_cached`]
+    notes: OnceLock<Vec<NoteName>>,
+}
+
+impl Scale {
+    /// Creates a scale from a tonic and either a [`ScaleType`] or a [`ScaleDefinition`]
+    pub fn new(tonic: NoteName, mode: impl Into<ScaleDefinition>) -> Self {
+        Scale {
+            tonic,
+            definition: mode.into(),
+            notes: OnceLock::new(),
+        }
+    }
+
+    /// Creates a scale from a tonic and a definition; the same as [`Scale::new`]
+    pub fn from_definition(tonic: NoteName, definition: ScaleDefinition) -> Self {
+        Scale::new(tonic, definition)
+    }
+
+    /// Creates a scale from a tonic and a list of intervals, under a custom name
+    ///
+    /// The intervals are taken from the tonic and should start with the
+    /// unison. A custom scale is equal to a built-in one only if the name
+    /// matches too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{Interval, NoteName, Scale};
+    ///
+    /// let intervals = [Interval::PERFECT_UNISON, Interval::MAJOR_THIRD, Interval::AUGMENTED_FIFTH];
+    /// let scale = Scale::custom(NoteName::C, "Augmented Triad", intervals.to_vec());
+    /// assert_eq!(scale.definition().name(), "Augmented Triad");
+    /// assert_eq!(scale.notes().len(), 3);
+    /// ```
+    pub fn custom(tonic: NoteName, name: impl Into<String>, intervals: Vec<Interval>) -> Self {
+        Scale::new(tonic, ScaleDefinition::custom(name, intervals))
+    }
+
+    /// Creates a scale from a tonic and the pitch classes it contains
+    ///
+    /// Each set bit becomes a degree, spelled greedily from the bottom up:
+    /// every degree takes a letter above the previous degree's if it can,
+    /// picking the interval closest to perfect, major or minor, and on a tie
+    /// the one with the lower letter. Letters repeat only when the scale has
+    /// more than seven notes or the letters run out. The tonic is always
+    /// included. If the spelled intervals match a built-in definition, that
+    /// definition is used; otherwise the scale is a custom one named
+    /// "Custom".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Scale, ScaleType, scales};
+    ///
+    /// let scale = Scale::from_bitmask(NoteName::D, scales::DORIAN.bitmask());
+    /// assert_eq!(scale, Scale::new(NoteName::D, ScaleType::Dorian));
+    /// ```
+    pub fn from_bitmask(tonic: NoteName, mask: scales::ScaleBitmask) -> Scale {
+        let mut intervals = vec![Interval::PERFECT_UNISON];
+        for semitones in 1..12 {
+            if !mask.contains(semitones) {
+                continue;
+            }
+            let last_steps = intervals.last().map_or(0, |i| i.steps());
+
+            // Spellings of this pitch class up to a double accidental
+            let spellings = (-15i8..=15)
+                .filter(|fifths| (fifths * 7 - semitones as i8).rem_euclid(12) == 0)
+                .map(|fifths| Interval::new(fifths, 0).simple());
+            let rank = |i: &Interval| (i.steps() <= last_steps, i.fifths().abs(), i.steps());
+            let interval = spellings
+                .min_by_key(rank)
+                .expect("every pitch class has a spelling");
+            intervals.push(interval);
+        }
+
+        let definition = scales::REGISTRY
+            .iter()
+            .find(|d| d.intervals() == intervals.as_slice())
+            .cloned()
+            .unwrap_or_else(|| ScaleDefinition::custom("Custom", intervals));
+        Scale::new(tonic, definition)
+    }
+
+    pub fn tonic(&self) -> NoteName {
+        self.tonic
+    }
+
+    pub fn definition(&self) -> &ScaleDefinition {
+        &self.definition
+    }
+
+    /// Returns the notes of the scale, starting from the tonic
+    ///
+    /// Notes are spelled from the definition's intervals, so the spelling
+    /// follows the tonic (G major has an F♯, F major has a B♭).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Scale, ScaleType};
+    ///
+    /// let f = NoteName::new(Letter::F, Accidental::Natural);
+    /// let notes = Scale::new(f, ScaleType::Major).notes();
+    /// assert_eq!(notes[3], NoteName::new(Letter::B, Accidental::Flat));
+    /// ```
+    pub fn notes(&self) -> Vec<NoteName> {
+        self.definition
+            .intervals()
+            .iter()
+            .map(|&i| self.tonic + i)
+            .collect()
+    }
+
+    /// Realizes the scale as ascending pitches over a number of octaves
+    ///
+    /// The tonic starts in `start_octave` and the final tonic an octave (or
+    /// more) above is included. Octave numbers change when the scale passes
+    /// from B to C, as in scientific pitch notation. The run stops at the
+    /// top of octave 127, the highest a [`Pitch`] can hold, so a large
+    /// `octaves` gives fewer pitches rather than wrapping around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Scale, ScaleType};
+    ///
+    /// let a_minor = Scale::new(NoteName::new(Letter::A, Accidental::Natural), ScaleType::NaturalMinor);
+    /// let pitches: Vec<String> = a_minor.pitches(3, 1).iter().map(|p| p.to_string()).collect();
+    /// assert_eq!(pitches, ["A3", "B3", "C4", "D4", "E4", "F4", "G4", "A4"]);
+    /// ```
+    pub fn pitches(&self, start_octave: i8, octaves: u8) -> Vec<Pitch> {
+        // Each degree's octave above the tonic, found at octave 0 so it can't overflow
+        let tonic = Pitch::new(self.tonic, 0);
+        let degrees: Vec<(NoteName, i16)> = self
+            .definition
+            .intervals()
+            .iter()
+            .map(|&interval| {
+                let pitch = tonic.transpose_by_interval(interval);
+                (pitch.name, pitch.octave as i16)
+            })
+            .collect();
+
+        let start = start_octave as i16;
+        (0..octaves as i16)
+            .flat_map(|octave| {
+                degrees
+                    .iter()
+                    .map(move |&(name, offset)| (name, start + octave + offset))
+            })
+            .chain(std::iter::once((self.tonic, start + octaves as i16)))
+            .map_while(|(name, octave)| i8::try_from(octave).ok().map(|o| Pitch::new(name, o)))
+            .collect()
+    }
+
+    /// Checks whether a note belongs to the scale, matching enharmonically
+    ///
+    /// This tests the note's pitch class against the scale's bitmask rather
+    /// than building the note list, so it is cheap enough for hot loops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Scale, ScaleType};
+    ///
+    /// let d_major = Scale::new(NoteName::new(Letter::D, Accidental::Natural), ScaleType::Major);
+    /// assert!(d_major.contains(&NoteName::new(Letter::F, Accidental::Sharp)));
+    /// assert!(d_major.contains(&NoteName::new(Letter::G, Accidental::Flat)));
+    /// assert!(!d_major.contains(&NoteName::new(Letter::F, Accidental::Natural)));
+    /// ```
+    pub fn contains(&self, note: &NoteName) -> bool {
+        // The bitmask is relative to the tonic, so measure the note from there
+        let semitones = (note.base_midi_number() - self.tonic.base_midi_number()).rem_euclid(12);
+        self.definition.bitmask().contains(semitones as u8)
+    }
+
+    /// Returns every triad and seventh chord that can be built from the scale
+    ///
+    /// Triads come first, then seventh chords, each ordered by root.
+    pub fn possible_chords(&self) -> Vec<Chord> {
+        let mut chords = self.triads();
+        chords.extend(self.sevenths());
+        chords
+    }
+
+    /// Returns the diatonic seventh chords paired with their roman numerals
+    ///
+    /// The numeral's case follows the triad (upper for major and augmented,
+    /// lower for minor and diminished) and the suffix marks the seventh, so
+    /// C major gives Imaj7, ii7, iii7, IVmaj7, V7, vi7 and viiø7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Scale, ScaleType};
+    ///
+    /// let a_minor = Scale::new(NoteName::new(Letter::A, Accidental::Natural), ScaleType::HarmonicMinor);
+    /// let labels: Vec<String> = a_minor.seventh_chords().into_iter().map(|(_, label)| label).collect();
+    /// assert_eq!(labels, ["imaj7", "iiø7", "III+maj7", "iv7", "V7", "VImaj7", "vii°7"]);
+    /// ```
+    pub fn seventh_chords(&self) -> Vec<(Chord, String)> {
+        self.sevenths()
+            .into_iter()
+            .filter_map(|chord| {
+                let label = self.roman_numeral(&chord)?;
+                Some((chord, label))
+            })
+            .collect()
+    }
+
+    /// Returns the triad stacked in thirds on a 1-based scale degree
+    ///
+    /// The third and fifth are the scale notes two and four degrees up,
+    /// wrapping past the octave. Returns `None` unless the scale has seven
+    /// notes, or when the stacked notes don't form a major, minor, diminished
+    /// or augmented triad.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{Chord, NoteName, Scale, ScaleType};
+    ///
+    /// let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    /// assert_eq!(c_major.chord_at_degree(2), Some(Chord::minor(NoteName::D)));
+    /// ```
+    pub fn chord_at_degree(&self, degree: usize) -> Option<Chord> {
+        let notes = self.notes_cached();
+        if notes.len() != 7 || !(1..=7).contains(&degree) {
+            return None;
+        }
+        let root = notes[degree - 1];
+        let third = (notes[(degree + 1) % 7] - root).simple().semitones();
+        let fifth = (notes[(degree + 3) % 7] - root).simple().semitones();
+        Some(Chord::new(root, triad_quality(third, fifth)?))
+    }
+
+    /// Returns the quality of the triad on the tonic
+    ///
+    /// Major for Ionian, Lydian and Mixolydian, minor for Dorian, Phrygian
+    /// and Aeolian, and diminished for Locrian. Returns `None` where
+    /// [`Scale::chord_at_degree`] does.
+    pub fn tonic_quality(&self) -> Option<ChordQuality> {
+        self.chord_at_degree(1).map(|chord| chord.quality())
+    }
+
+    /// Returns the quality of the triad on each degree, in order
+    ///
+    /// C major gives major, minor, minor, major, major, minor, diminished.
+    /// Degrees without a triad from [`Scale::chord_at_degree`] are skipped,
+    /// so scales without seven notes give an empty list.
+    pub fn chord_qualities(&self) -> Vec<ChordQuality> {
+        (1..=self.notes_cached().len())
+            .filter_map(|degree| self.chord_at_degree(degree))
+            .map(|chord| chord.quality())
+            .collect()
+    }
+
+    /// Returns the roman-numeral label of a chord built on a scale degree
+    ///
+    /// Returns `None` when the chord's root is not in the scale, or when the
+    /// scale has more than seven degrees.
+    pub fn roman_numeral(&self, chord: &Chord) -> Option<String> {
+        const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+        let numeral = NUMERALS.get(self.degree_of(&chord.root())? - 1)?;
+        let numeral = match chord.quality() {
+            ChordQuality::Minor | ChordQuality::Diminished => numeral.to_lowercase(),
+            _ => numeral.to_string(),
+        };
+        Some(format!("{}{}", numeral, chord.roman_numeral_suffix()))
+    }
+
+    /// Scores how bright the scale sounds, from the positions of its degrees on the line of fifths
+    ///
+    /// Raising a degree by a semitone moves it seven fifths up, so each
+    /// raised degree adds 7 and each lowered one takes 7 away. Among the
+    /// diatonic modes this orders Lydian (21) down to Locrian (-21), one
+    /// altered degree apart. The score doesn't depend on the tonic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Scale, ScaleType};
+    ///
+    /// let lydian = Scale::new(NoteName::F, ScaleType::Lydian);
+    /// let ionian = Scale::new(NoteName::C, ScaleType::Major);
+    /// assert!(lydian.brightness() > ionian.brightness());
+    /// ```
+    pub fn brightness(&self) -> i32 {
+        self.definition
+            .intervals()
+            .iter()
+            .map(|i| i.fifths() as i32)
+            .sum()
+    }
+
+    /// Returns the chromatic notes that are not in the scale, ascending from the tonic
+    ///
+    /// Each missing pitch class is spelled with at most one sharp or flat,
+    /// choosing the spelling that sits closest to the scale's own notes on
+    /// the line of fifths. This follows the key signature: flat keys lean to
+    /// flats and sharp keys to sharps, and C major gets the usual chromatic
+    /// spellings C♯, E♭, F♯, G♯ and B♭. Exact ties go to the side of the key
+    /// signature, sharps for C major.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Scale, ScaleType};
+    ///
+    /// let f_major = Scale::new(NoteName::F, ScaleType::Major);
+    /// let names: Vec<String> = f_major.complement().iter().map(|n| n.to_string()).collect();
+    /// assert_eq!(names, ["F♯", "A♭", "B", "D♭", "E♭"]);
+    /// ```
+    pub fn complement(&self) -> Vec<NoteName> {
+        let notes = self.notes_cached();
+        if notes.is_empty() {
+            return Vec::new();
+        }
+        let centre = notes.iter().map(|n| n.fifths() as f64).sum::<f64>() / notes.len() as f64;
+        // C major is centred on D, two fifths above C
+        let prefer_sharps = centre >= 2.0;
+
+        let spellings: Vec<NoteName> = Letter::all()
+            .into_iter()
+            .flat_map(|letter| {
+                [Accidental::Flat, Accidental::Natural, Accidental::Sharp]
+                    .map(|accidental| NoteName::new(letter, accidental))
+            })
+            .collect();
+
+        let tonic = self.tonic.pitch_class();
+        (0..12)
+            .map(|offset| (tonic + offset) % 12)
+            .filter(|&pc| !notes.iter().any(|n| n.pitch_class() == pc))
+            .filter_map(|pc| {
+                spellings
+                    .iter()
+                    .filter(|n| n.pitch_class() == pc)
+                    .min_by(|a, b| {
+                        let distance = |n: &NoteName| (n.fifths() as f64 - centre).abs();
+                        let sharper = b.fifths().cmp(&a.fifths());
+                        distance(a).total_cmp(&distance(b)).then(if prefer_sharps {
+                            sharper
+                        } else {
+                            sharper.reverse()
+                        })
+                    })
+                    .copied()
+            })
+            .collect()
+    }
+
+    /// Returns the notes that set this scale apart from the parallel major or minor
+    ///
+    /// Scales with a major third are compared with the major scale on the
+    /// same tonic and the rest with the natural minor. The characteristic
+    /// notes are the degrees that differ: Lydian's raised fourth, Dorian's
+    /// natural sixth, Mixolydian's lowered seventh. Major and natural minor
+    /// themselves have none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Scale, ScaleType};
+    ///
+    /// let f_lydian = Scale::new(NoteName::F, ScaleType::Lydian);
+    /// assert_eq!(f_lydian.characteristic_notes(), [NoteName::B]);
+    /// ```
+    pub fn characteristic_notes(&self) -> Vec<NoteName> {
+        let intervals = self.definition.intervals();
+        let parallel = if intervals.contains(&Interval::MAJOR_THIRD) {
+            scales::IONIAN
+        } else {
+            scales::AEOLIAN
+        };
+
+        intervals
+            .iter()
+            .filter(|&&interval| {
+                parallel
+                    .intervals()
+                    .iter()
+                    .any(|p| p.steps() == interval.steps() && *p != interval)
+            })
+            .map(|&interval| self.tonic + interval)
+            .collect()
+    }
+
+    /// Returns the semitone steps between consecutive scale tones, ending back at the octave
+    ///
+    /// The steps always add up to 12. Major gives the familiar
+    /// whole-whole-half-whole-whole-whole-half pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Scale, ScaleType};
+    ///
+    /// let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    /// assert_eq!(c_major.step_pattern(), [2, 2, 1, 2, 2, 2, 1]);
+    /// ```
+    pub fn step_pattern(&self) -> Vec<u8> {
+        let notes = self.notes_cached();
+        notes
+            .iter()
+            .zip(notes.iter().cycle().skip(1))
+            .map(|(from, to)| {
+                (to.base_midi_number() - from.base_midi_number()).rem_euclid(12) as u8
+            })
+            .collect()
+    }
+
+    /// Returns the scale's notes, spelling them only on the first call
+    ///
+    /// [`Scale::notes`] builds a fresh vector every time, which adds up in
+    /// analysis loops that look up many notes in the same scale. The cached
+    /// list is kept for the lifetime of the scale, trading one allocation
+    /// per scale for not repeating the work.
+    pub fn notes_cached(&self) -> &[NoteName] {
+        self.notes.get_or_init(|| self.notes())
+    }
+
+    /// Returns the note on a 1-based scale degree, or `None` if the scale has no such degree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Scale, ScaleType};
+    ///
+    /// let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    /// assert_eq!(c_major.get(5), Some(NoteName::G));
+    /// assert_eq!(c_major.get(8), None);
+    /// ```
+    pub fn get(&self, degree: usize) -> Option<NoteName> {
+        self.notes_cached().get(degree.checked_sub(1)?).copied()
+    }
+
+    /// Returns the scale with one degree raised by a chromatic semitone
+    ///
+    /// Degrees are 1-based. If the altered intervals match a built-in
+    /// definition, that definition is used; otherwise the result is a custom
+    /// scale named after the alteration in ASCII, such as "Ionian #4".
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is 0 or greater than the number of notes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Scale, ScaleType};
+    ///
+    /// let a_minor = Scale::new(NoteName::A, ScaleType::NaturalMinor);
+    /// let harmonic = Scale::new(NoteName::A, ScaleType::HarmonicMinor);
+    /// assert_eq!(a_minor.with_raised_degree(7), harmonic);
+    /// ```
+    pub fn with_raised_degree(&self, degree: u8) -> Scale {
+        self.with_altered_degree(degree, 1)
+    }
+
+    /// Returns the scale with one degree lowered by a chromatic semitone
+    ///
+    /// Works like [`Scale::with_raised_degree`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is 0 or greater than the number of notes.
+    pub fn with_lowered_degree(&self, degree: u8) -> Scale {
+        self.with_altered_degree(degree, -1)
+    }
+
+    fn with_altered_degree(&self, degree: u8, direction: i8) -> Scale {
+        let mut intervals = self.definition.intervals().to_vec();
+        let count = intervals.len();
+        let index = (degree as usize)
+            .checked_sub(1)
+            .filter(|&i| i < count)
+            .unwrap_or_else(|| panic!("scale degree {} out of range 1..={}", degree, count));
+
+        // An augmented unison moves a note by a semitone without changing its letter
+        let chromatic = Interval::new(7 * direction, -4 * direction);
+        intervals[index] = intervals[index] + chromatic;
+
+        let definition = scales::REGISTRY
+            .iter()
+            .find(|d| d.intervals() == intervals.as_slice())
+            .cloned()
+            .unwrap_or_else(|| {
+                // Names stay the same whatever the `utf8_symbols` feature says
+                let symbol = if direction > 0 { "#" } else { "b" };
+                let name = format!("{} {}{}", self.definition.name(), symbol, degree);
+                ScaleDefinition::custom(name, intervals)
+            });
+        Scale::new(self.tonic, definition)
+    }
+
+    /// Checks whether this scale is a mode of `parent`: the same pitch classes from a different tonic
+    ///
+    /// Spelling is ignored, so enharmonic scales count as the same set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Scale, ScaleType};
+    ///
+    /// let c_ionian = Scale::new(NoteName::C, ScaleType::Major);
+    /// let d_dorian = Scale::new(NoteName::D, ScaleType::Dorian);
+    /// assert!(d_dorian.is_mode_of(&c_ionian));
+    /// assert!(!c_ionian.is_mode_of(&c_ionian));
+    /// ```
+    pub fn is_mode_of(&self, parent: &Scale) -> bool {
+        let offset = (self.tonic.pitch_class() + 12 - parent.tonic.pitch_class()) % 12;
+        offset != 0 && parent.definition.bitmask().rotate(offset) == self.definition.bitmask()
+    }
+
+    /// Moves a pitch to the nearest pitch in the scale, spelled as in the scale
+    ///
+    /// Pitches already in the scale keep their height and take the scale's
+    /// spelling. When two scale tones are equally close, the upper one wins,
+    /// so C♯4 snaps to D4 in C major.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{pitch, NoteName, Scale, ScaleType};
+    ///
+    /// let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    /// assert_eq!(c_major.snap(pitch!("C#4")), pitch!("D4"));
+    /// assert_eq!(c_major.snap(pitch!("A#3")), pitch!("B3"));
+    /// ```
+    pub fn snap(&self, pitch: Pitch) -> Pitch {
+        let semitones = pitch.midi_number();
+        self.notes_cached()
+            .iter()
+            .map(|&note| {
+                let up = (note.base_midi_number() as i16 - semitones).rem_euclid(12);
+                let offset = if up <= 12 - up { up } else { up - 12 };
+                (offset.abs(), offset < 0, note, offset)
+            })
+            .min_by_key(|&(distance, below, _, _)| (distance, below))
+            .map_or(pitch, |(_, _, note, offset)| {
+                Pitch::from_midi_number(note, semitones + offset)
+            })
+    }
+
+    /// Returns the pentatonic scale left after dropping the two avoid degrees
+    ///
+    /// Only the diatonic modes have one. Ionian, Lydian and Mixolydian drop
+    /// degrees 4 and 7 to give the major pentatonic; Dorian, Phrygian and
+    /// Aeolian drop degrees 2 and 6 to give the minor pentatonic. Locrian and
+    /// every other scale, such as Lydian Dominant, give `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Scale, ScaleType, scales};
+    ///
+    /// let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    /// assert_eq!(c_major.pentatonic(), Some(Scale::new(NoteName::C, scales::MAJOR_PENTATONIC)));
+    /// ```
+    pub fn pentatonic(&self) -> Option<Scale> {
+        let intervals = self.definition.intervals();
+        if !scales::DIATONIC_MODES
+            .iter()
+            .any(|mode| mode.intervals() == intervals)
+        {
+            return None;
+        }
+        [scales::MAJOR_PENTATONIC, scales::MINOR_PENTATONIC]
+            .into_iter()
+            .find(|pentatonic| pentatonic.intervals().iter().all(|i| intervals.contains(i)))
+            .map(|pentatonic| Scale::new(self.tonic, pentatonic))
+    }
+
+    /// Returns the 1-based scale degree of a note, if it belongs to the scale
+    ///
+    /// Notes are matched enharmonically, so F♭ is found as degree 3 of C major.
+    pub fn degree_of(&self, note: &NoteName) -> Option<usize> {
+        self.notes_cached()
+            .iter()
+            .position(|n| n.is_enharmonic_with(note))
+            .map(|i| i + 1)
+    }
+
+    /// Returns the 1-based diatonic degree of a note by its letter distance from the tonic
+    ///
+    /// Unlike [`Scale::degree_of`], this doesn't depend on how many notes
+    /// the scale has: A is degree 6 of C major pentatonic, not degree 5. The
+    /// note is matched enharmonically and counted as the scale spells it.
+    pub(crate) fn letter_degree_of(&self, note: &NoteName) -> Option<u8> {
+        let spelled = self.notes_cached()[self.degree_of(note)? - 1];
+        Some(self.tonic.letter_distance_to(&spelled) as u8 + 1)
+    }
+
+    /// Returns the dominant seventh chord that resolves to a scale degree (V7 of that degree)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_degree` is 0 or larger than the number of notes in the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Scale, ScaleType, SeventhType};
+    ///
+    /// let c_major = Scale::new(NoteName::new(Letter::C, Accidental::Natural), ScaleType::Major);
+    /// let d7 = Chord::seventh(NoteName::new(Letter::D, Accidental::Natural), SeventhType::Dominant);
+    /// assert_eq!(c_major.secondary_dominant(5), d7);
+    /// ```
+    pub fn secondary_dominant(&self, target_degree: u8) -> Chord {
+        let notes = self.notes();
+        assert!(
+            (1..=notes.len()).contains(&(target_degree as usize)),
+            "scale degree {} is out of range",
+            target_degree
+        );
+
+        let target = notes[target_degree as usize - 1];
+        Chord::seventh(target + Interval::PERFECT_FIFTH, SeventhType::Dominant)
+    }
+
+    /// Returns the Neapolitan chord: a major triad on the lowered second degree (♭II)
+    ///
+    /// The chord is usually played in first inversion (the "Neapolitan sixth");
+    /// this returns it in root position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Scale, ScaleType};
+    ///
+    /// let c_minor = Scale::new(NoteName::new(Letter::C, Accidental::Natural), ScaleType::NaturalMinor);
+    /// let d_flat = NoteName::new(Letter::D, Accidental::Flat);
+    /// assert_eq!(c_minor.neapolitan(), Chord::major(d_flat));
+    /// ```
+    pub fn neapolitan(&self) -> Chord {
+        Chord::major(self.tonic + Interval::MINOR_SECOND)
+    }
+
+    /// Reflects a chord across the axis between the tonic and its fifth (negative harmony)
+    ///
+    /// In C the axis lies between E♭ and E: G maps to C, B to A♭ and D to F, so a
+    /// G major chord becomes F minor. The reflection is done on the line of
+    /// fifths, which keeps the spelling sensible, and the root of the result is
+    /// found with [`Chord::from_notes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Scale, ScaleType};
+    ///
+    /// let c_major = Scale::new(NoteName::new(Letter::C, Accidental::Natural), ScaleType::Major);
+    /// let g_major = Chord::major(NoteName::new(Letter::G, Accidental::Natural));
+    /// let f_minor = Chord::minor(NoteName::new(Letter::F, Accidental::Natural));
+    /// assert_eq!(c_major.negative_harmony(&g_major), f_minor);
+    /// ```
+    pub fn negative_harmony(&self, chord: &Chord) -> Chord {
+        let axis = 2 * self.tonic.fifths() + 1;
+        chord
+            .notes()
+            .iter()
+            .map(|note| NoteName::from_fifths((axis - note.fifths()).into()))
+            .collect()
+    }
+
+    /// Rates how much melodic tension a note has within this scale
+    ///
+    /// Degrees 1, 3 and 5 are stable, the other scale degrees tend to resolve
+    /// to a neighbouring stable tone, and notes outside the scale are unstable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Scale, ScaleType, TensionRating};
+    ///
+    /// let c_major = Scale::new(NoteName::new(Letter::C, Accidental::Natural), ScaleType::Major);
+    /// let b = NoteName::new(Letter::B, Accidental::Natural);
+    /// assert_eq!(c_major.tension(&b), TensionRating::Tendency);
+    /// ```
+    pub fn tension(&self, note: &NoteName) -> TensionRating {
+        match self.degree_of(note) {
+            Some(1 | 3 | 5) => TensionRating::Stable,
+            Some(_) => TensionRating::Tendency,
+            None => TensionRating::Unstable,
+        }
+    }
+
+    /// Finds the parallel mode a non-diatonic chord is borrowed from
+    ///
+    /// Returns `None` when the chord is diatonic to this scale or not diatonic
+    /// to any of the seven modes on the same tonic. When several modes fit,
+    /// the one sharing the most notes with this scale wins, so B♭ major in
+    /// C major comes from C Mixolydian rather than C Aeolian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Scale, ScaleType, scales};
+    ///
+    /// let c = NoteName::new(Letter::C, Accidental::Natural);
+    /// let f_minor = Chord::minor(NoteName::new(Letter::F, Accidental::Natural));
+    /// let source = Scale::new(c, ScaleType::Major).is_borrowed(&f_minor);
+    /// assert_eq!(source, Some(Scale::new(c, scales::AEOLIAN)));
+    /// ```
+    pub fn is_borrowed(&self, chord: &Chord) -> Option<Scale> {
+        let notes = chord.notes();
+        let fits = |scale: &Scale| notes.iter().all(|note| scale.contains(note));
+        if fits(self) {
+            return None;
+        }
+
+        let bits = self.definition.bitmask().bits();
+        scales::DIATONIC_MODES
+            .iter()
+            .map(|mode| Scale::new(self.tonic, mode.clone()))
+            .filter(|scale| *scale != *self && fits(scale))
+            .min_by_key(|scale| (scale.definition.bitmask().bits() ^ bits).count_ones())
+    }
+
+    /// Classifies a chord by the harmonic function of its root in this scale
+    ///
+    /// Chords on degrees 1, 3 and 6 have tonic function, degrees 2 and 4
+    /// subdominant, and degrees 5 and 7 dominant. Degrees are counted by
+    /// letter from the tonic rather than by position in the scale, so scales
+    /// with fewer or more than seven notes classify their chords the same
+    /// way. Chords whose root is not in the scale return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Scale, ScaleType, HarmonicFunction, SeventhType};
+    ///
+    /// let c_major = Scale::new(NoteName::new(Letter::C, Accidental::Natural), ScaleType::Major);
+    /// let g7 = Chord::seventh(NoteName::new(Letter::G, Accidental::Natural), SeventhType::Dominant);
+    /// assert_eq!(c_major.harmonic_function(&g7), Some(HarmonicFunction::Dominant));
+    /// ```
+    pub fn harmonic_function(&self, chord: &Chord) -> Option<HarmonicFunction> {
+        match self.letter_degree_of(&chord.root())? {
+            1 | 3 | 6 => Some(HarmonicFunction::Tonic),
+            2 | 4 => Some(HarmonicFunction::Subdominant),
+            5 | 7 => Some(HarmonicFunction::Dominant),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scale")
+            .field("tonic", &self.tonic)
+            .field("definition", &self.definition)
+            .finish()
+    }
+}
+
+// Equality ignores whether the note cache has been filled
+impl PartialEq for Scale {
+    fn eq(&self, other: &Self) -> bool {
+        self.tonic == other.tonic && self.definition == other.definition
+    }
+}
+
+impl Eq for Scale {}
+
+/// A scale equals a definition with the same pitch classes, whatever its tonic or name
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Scale, ScaleType, scales};
+///
+/// let c_major = Scale::new(NoteName::C, ScaleType::Major);
+/// assert_eq!(c_major, scales::IONIAN);
+/// assert_ne!(c_major, scales::DORIAN);
+/// ```
+impl PartialEq<ScaleDefinition> for Scale {
+    fn eq(&self, other: &ScaleDefinition) -> bool {
+        self.definition.bitmask() == other.bitmask()
+    }
+}
+
+impl PartialEq<Scale> for ScaleDefinition {
+    fn eq(&self, other: &Scale) -> bool {
+        other == self
+    }
+}
+
+/// The role a chord plays within a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HarmonicFunction {
+    Tonic,
+    Subdominant,
+    Dominant,
+}
+
+/// How strongly a note wants to resolve within a scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TensionRating {
+    /// Tonic triad tones (degrees 1, 3 and 5)
+    Stable,
+
+    /// Other scale tones, which lean towards a stable neighbour
+    Tendency,
+
+    /// Chromatic notes outside the scale
+    Unstable,
+}
+
+/// A musical key (combination of tonic and mode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Key {
+    tonic: NoteName,
+    mode: Mode, // Usually just Major or Minor
+}
+
+impl Key {
+    pub fn new(tonic: NoteName, mode: Mode) -> Self {
+        Key { tonic, mode }
+    }
+
+    pub fn major(tonic: NoteName) -> Self {
+        Key::new(tonic, Mode::Major)
+    }
+
+    pub fn minor(tonic: NoteName) -> Self {
+        Key::new(tonic, Mode::Minor)
+    }
+
+    pub fn tonic(&self) -> NoteName {
+        self.tonic
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns the sharps or flats of the key signature, in signature order
+    ///
+    /// A signature has at most seven entries, one per letter. Theoretical keys
+    /// with more than seven sharps or flats double the first letters instead,
+    /// so G♯ major starts with F𝄪 rather than listing F twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Key};
+    ///
+    /// let key = Key::minor(NoteName::new(Letter::G, Accidental::Natural));
+    /// let flats: Vec<String> = key.accidentals().iter().map(|n| n.to_string()).collect();
+    /// assert_eq!(flats, ["B♭", "E♭"]);
+    /// ```
+    pub fn accidentals(&self) -> Vec<NoteName> {
+        let fifths = self.signature_fifths();
+        let count = fifths.abs().min(7);
+        // Letters past the seventh accidental come round again, doubled
+        let doubled = |i: i8| if i < fifths.abs() - 7 { 7 } else { 0 };
+        if fifths >= 0 {
+            (0..count)
+                .map(|i| NoteName::from_fifths((6 + i + doubled(i)).into()))
+                .collect()
+        } else {
+            (0..count)
+                .map(|i| NoteName::from_fifths((-2 - i - doubled(i)).into()))
+                .collect()
+        }
+    }
+
+    /// Returns the scale implied by the key signature
+    ///
+    /// Major keys give the Ionian scale and minor keys the Aeolian (natural
+    /// minor) scale, rooted on the key's tonic.
     ///
     /// # Examples
     ///
     /// ```
-    /// use chordy::{NoteName, Letter, Accidental};
-    /// let c_natural = NoteName::new(Letter::C, Accidental::Natural);
-    /// assert_eq!(c_natural.base_midi_number(), 0);
+    /// use chordy::{NoteName, Letter, Accidental, Key};
     ///
-    /// let f_sharp = NoteName::new(Letter::F, Accidental::Sharp);
-    /// assert_eq!(f_sharp.base_midi_number(), 6);
+    /// let g_major = Key::major(NoteName::new(Letter::G, Accidental::Natural));
+    /// let notes = g_major.scale().notes();
+    /// assert_eq!(notes[6], NoteName::new(Letter::F, Accidental::Sharp));
+    /// ```
+    pub fn scale(&self) -> Scale {
+        let definition = match self.mode {
+            Mode::Major => scales::IONIAN,
+            Mode::Minor => scales::AEOLIAN,
+        };
+        Scale::new(self.tonic, definition)
+    }
+
+    /// Returns the relative key, which shares this key's signature
+    ///
+    /// The relative minor sits on the sixth degree of a major key; the
+    /// relative major sits on the third degree of a minor key.
+    ///
+    /// # Examples
     ///
-    /// let b_flat = NoteName::new(Letter::B, Accidental::Flat);
-    /// assert_eq!(b_flat.base_midi_number(), 10);
     /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Key};
     ///
-    pub fn base_midi_number(&self) -> i8 {
-        self.letter.base_midi_number() + self.accidental.semitone_offset()
+    /// let e_flat_major = Key::major(NoteName::new(Letter::E, Accidental::Flat));
+    /// let c_minor = Key::minor(NoteName::new(Letter::C, Accidental::Natural));
+    /// assert_eq!(e_flat_major.relative(), c_minor);
+    /// assert_eq!(c_minor.relative(), e_flat_major);
+    /// ```
+    pub fn relative(&self) -> Key {
+        match self.mode {
+            Mode::Major => Key::minor(self.tonic + Interval::MAJOR_SIXTH),
+            Mode::Minor => Key::major(self.tonic + Interval::MINOR_THIRD),
+        }
     }
 
-    /// Checks if two note names are enharmonically equivalent
-    pub fn is_enharmonic_with(&self, other: &Self) -> bool {
-        // Notes are enharmonically equivalent if they represent the same pitch
-        self.base_midi_number() % 12 == other.base_midi_number() % 12
+    /// Returns the parallel key, which shares this key's tonic in the other mode
+    pub fn parallel(&self) -> Key {
+        match self.mode {
+            Mode::Major => Key::minor(self.tonic),
+            Mode::Minor => Key::major(self.tonic),
+        }
     }
-}
 
-impl fmt::Display for NoteName {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.accidental {
-            Accidental::Natural => write!(f, "{}", self.letter),
-            _ => write!(f, "{}{}", self.letter, self.accidental),
+    /// Returns the same-sounding key spelled with the other kind of accidental
+    ///
+    /// Only keys whose respelling needs at most seven sharps or flats have
+    /// one: B/C♭, F♯/G♭ and C♯/D♭ major, and their relative minors. Keys
+    /// beyond seven accidentals map back into that range, so G♯ major gives
+    /// A♭ major.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{note, Key};
+    ///
+    /// assert_eq!(Key::major(note!("Gb")).enharmonic_equivalent(), Some(Key::major(note!("F#"))));
+    /// assert_eq!(Key::major(note!("C")).enharmonic_equivalent(), None);
+    /// ```
+    pub fn enharmonic_equivalent(&self) -> Option<Key> {
+        let fifths = self.signature_fifths();
+        let shift = 12 * fifths.signum();
+        if shift == 0 || (fifths - shift).abs() > 7 {
+            return None;
         }
+        let tonic = NoteName::from_fifths((self.tonic.fifths() - shift).into());
+        Some(Key::new(tonic, self.mode))
     }
-}
 
-/// A specific pitch with both note name and octave
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Pitch {
-    name: NoteName,
-    octave: i8,
-}
+    /// Iterates over the twelve major keys in circle-of-fifths order from C
+    ///
+    /// Keys are spelled with at most six sharps or flats, so the circle runs
+    /// C, G, D, A, E, B, F♯, D♭, A♭, E♭, B♭, F.
+    pub fn circle_of_fifths() -> impl Iterator<Item = Key> {
+        let c_major = Key::major(NoteName::new(Letter::C, Accidental::Natural));
+        std::iter::successors(Some(c_major), |key| Some(key.next_sharpward())).take(12)
+    }
 
-impl Pitch {
-    pub fn new(name: NoteName, octave: i8) -> Self {
-        Pitch { name, octave }
+    /// Returns the key a fifth above, with one more sharp (or one fewer flat)
+    ///
+    /// Keys that would need more than six sharps are respelled with flats,
+    /// so stepping sharpward from F♯ major gives D♭ major.
+    pub fn next_sharpward(&self) -> Key {
+        self.step_fifths(1)
     }
 
-    /// Returns the full MIDI note number for this pitch
-    pub fn midi_number(&self) -> i8 {
-        // MIDI octaves start at -2, where C-2 is note 0
-        self.name.base_midi_number() + ((self.octave + 2) * 12)
+    /// Returns the key a fifth below, with one more flat (or one fewer sharp)
+    ///
+    /// Keys that would need more than six flats are respelled with sharps,
+    /// so stepping flatward from G♭ major gives B major.
+    pub fn next_flatward(&self) -> Key {
+        self.step_fifths(-1)
     }
 
-    /// Checks if two pitches represent the same frequency
-    pub fn is_enharmonic_with(&self, other: &Self) -> bool {
-        self.midi_number() == other.midi_number()
+    fn step_fifths(&self, step: i8) -> Key {
+        let mut fifths = self.tonic.fifths() + step;
+        let signature = self.signature_fifths() + step;
+        if signature > 6 {
+            fifths -= 12;
+        } else if signature < -6 {
+            fifths += 12;
+        }
+        Key::new(NoteName::from_fifths(fifths.into()), self.mode)
     }
-}
 
-impl fmt::Display for Pitch {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.name, self.octave)
+    /// Number of sharps (positive) or flats (negative) in the key signature
+    fn signature_fifths(&self) -> i8 {
+        match self.mode {
+            Mode::Major => self.tonic.fifths(),
+            Mode::Minor => self.tonic.fifths() - 3,
+        }
     }
-}
 
-/// A chord with a root note and quality
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Chord {
-    root: NoteName,
-    quality: ChordQuality,
-    extensions: Vec<ChordExtension>,
-}
+    /// Labels each chord with its Nashville number in this key
+    ///
+    /// The number is the scale degree of the chord's root, prefixed with `b` or
+    /// `#` when the root is altered from the key's scale, and followed by a
+    /// quality suffix (`m`, `°`, `+`, `sus4`, `sus2`) and a seventh suffix
+    /// (`7`, `maj7`, `ø7`, `°7`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{NoteName, Letter, Accidental, Chord, Key, SeventhType};
+    ///
+    /// let n = |letter| NoteName::new(letter, Accidental::Natural);
+    /// let key = Key::major(n(Letter::C));
+    /// let chords = [
+    ///     Chord::major(n(Letter::C)),
+    ///     Chord::minor(n(Letter::A)),
+    ///     Chord::major(n(Letter::F)),
+    ///     Chord::seventh(n(Letter::G), SeventhType::Dominant),
+    /// ];
+    /// assert_eq!(key.nashville_numbers(&chords), "1 6m 4 57");
+    /// ```
+    pub fn nashville_numbers(&self, chords: &[Chord]) -> String {
+        let scale = self.scale().notes();
 
-/// A scale with a tonic and mode
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Scale {
-    tonic: NoteName,
-    mode: ScaleType,
-}
+        let labels: Vec<String> = chords
+            .iter()
+            .map(|chord| {
+                let degree = (chord.root - self.tonic).steps() as usize;
+                let diatonic = scale[degree];
+                let shift = (chord.root.base_midi_number() - diatonic.base_midi_number() + 6)
+                    .rem_euclid(12)
+                    - 6;
+                let prefix = match shift {
+                    ..=-1 => "b".repeat(shift.unsigned_abs() as usize),
+                    _ => "#".repeat(shift as usize),
+                };
 
-impl Scale {
-    pub fn new(tonic: NoteName, mode: ScaleType) -> Self {
-        Scale { tonic, mode }
-    }
+                format!("{}{}{}", prefix, degree + 1, chord.nashville_suffix())
+            })
+            .collect();
 
-    pub fn notes(&self) -> Vec<NoteName> {
-        // Generate notes based on tonic and mode
-        // This is a placeholder implementation
-        vec![self.tonic]
+        labels.join(" ")
     }
 }
 
-/// A musical key (combination of tonic and mode)
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Key {
-    tonic: NoteName,
-    mode: Mode, // Usually just Major or Minor
-}
-
 /// Musical letter names A through G, with numeric backing
 /// representing their position in the chromatic scale.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -160,13 +3765,51 @@ impl fmt::Display for Letter {
 }
 
 impl Letter {
+    /// Returns all letters in order, starting from C
+    pub fn all() -> [Letter; 7] {
+        [
+            Letter::C,
+            Letter::D,
+            Letter::E,
+            Letter::F,
+            Letter::G,
+            Letter::A,
+            Letter::B,
+        ]
+    }
+
     /// Returns the base MIDI note number for this letter in octave 0
     pub fn base_midi_number(&self) -> i8 {
         *self as i8
     }
 
+    /// Returns the position of this letter on the line of fifths (F = -1, C = 0 ... B = 5)
+    pub fn fifths(&self) -> i8 {
+        match self {
+            Letter::F => -1,
+            Letter::C => 0,
+            Letter::G => 1,
+            Letter::D => 2,
+            Letter::A => 3,
+            Letter::E => 4,
+            Letter::B => 5,
+        }
+    }
+
+    fn from_fifths(fifths: i8) -> Self {
+        match fifths {
+            -1 => Letter::F,
+            0 => Letter::C,
+            1 => Letter::G,
+            2 => Letter::D,
+            3 => Letter::A,
+            4 => Letter::E,
+            _ => Letter::B,
+        }
+    }
+
     /// Gets the next letter in the sequence (wrapping from G to A)
-    pub fn _next(&self) -> Self {
+    pub fn next(&self) -> Self {
         match self {
             Letter::A => Letter::B,
             Letter::B => Letter::C,
@@ -179,7 +3822,7 @@ impl Letter {
     }
 
     /// Gets the previous letter in the sequence (wrapping from A to G)
-    pub fn _prev(&self) -> Self {
+    pub fn prev(&self) -> Self {
         match self {
             Letter::A => Letter::G,
             Letter::B => Letter::A,
@@ -194,21 +3837,91 @@ impl Letter {
 
 /// Accidentals that modify the pitch of a note,
 /// with numeric backing representing semitone shifts.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 #[repr(i8)]
 pub enum Accidental {
     DoubleFlat = -2,
     Flat = -1,
+    #[default]
     Natural = 0,
     Sharp = 1,
     DoubleSharp = 2,
 }
 
 impl Accidental {
+    /// Returns all accidentals, from double flat to double sharp
+    pub fn all() -> [Accidental; 5] {
+        [
+            Accidental::DoubleFlat,
+            Accidental::Flat,
+            Accidental::Natural,
+            Accidental::Sharp,
+            Accidental::DoubleSharp,
+        ]
+    }
+
     /// Returns the semitone offset for this accidental
     pub fn semitone_offset(&self) -> i8 {
         *self as i8
     }
+
+    /// Returns the accidental for a semitone offset, if it is within a double flat or double sharp
+    pub fn from_semitone_offset(offset: i8) -> Option<Accidental> {
+        match offset {
+            -2..=2 => Some(Accidental::from_shift(offset)),
+            _ => None,
+        }
+    }
+
+    /// Returns the accidental a semitone higher, or `None` for a double sharp
+    pub fn raised(&self) -> Option<Accidental> {
+        Accidental::from_semitone_offset(self.semitone_offset() + 1)
+    }
+
+    /// Returns the accidental a semitone lower, or `None` for a double flat
+    pub fn lowered(&self) -> Option<Accidental> {
+        Accidental::from_semitone_offset(self.semitone_offset() - 1)
+    }
+
+    /// Returns the accidental for a semitone shift, clamped to a double flat or double sharp
+    pub(crate) fn from_shift(shift: i8) -> Self {
+        match shift {
+            i8::MIN..=-2 => Accidental::DoubleFlat,
+            -1 => Accidental::Flat,
+            0 => Accidental::Natural,
+            1 => Accidental::Sharp,
+            2..=i8::MAX => Accidental::DoubleSharp,
+        }
+    }
+}
+
+impl Accidental {
+    /// Returns the accidental written with ASCII characters ("#", "bb"), whatever the features
+    pub fn to_string_ascii(&self) -> String {
+        self.symbol(false).to_string()
+    }
+
+    /// Returns the accidental written with Unicode symbols ("♯", "𝄫"), whatever the features
+    pub fn to_string_unicode(&self) -> String {
+        self.symbol(true).to_string()
+    }
+
+    fn symbol(&self, unicode: bool) -> &'static str {
+        use crate::symbols::{ascii, unicode as utf8};
+
+        match (self, unicode) {
+            (Accidental::Flat, false) => ascii::FLAT,
+            (Accidental::Sharp, false) => ascii::SHARP,
+            (Accidental::Natural, false) => ascii::NATURAL,
+            (Accidental::DoubleFlat, false) => ascii::DOUBLE_FLAT,
+            (Accidental::DoubleSharp, false) => ascii::DOUBLE_SHARP,
+            (Accidental::Flat, true) => utf8::FLAT,
+            (Accidental::Sharp, true) => utf8::SHARP,
+            (Accidental::Natural, true) => utf8::NATURAL,
+            (Accidental::DoubleFlat, true) => utf8::DOUBLE_FLAT,
+            (Accidental::DoubleSharp, true) => utf8::DOUBLE_SHARP,
+        }
+    }
 }
 
 impl fmt::Display for Accidental {
@@ -251,6 +3964,361 @@ pub enum ChordQuality {
     // etc.
 }
 
+impl ChordQuality {
+    /// Returns the quality spelled out in English ("major", "suspended fourth")
+    pub fn full_name(&self) -> &'static str {
+        match self {
+            ChordQuality::Major => "major",
+            ChordQuality::Minor => "minor",
+            ChordQuality::Diminished => "diminished",
+            ChordQuality::Augmented => "augmented",
+            ChordQuality::Sus2 => "suspended second",
+            ChordQuality::Sus4 => "suspended fourth",
+        }
+    }
+
+    /// Returns the intervals of the triad with this quality, including the root
+    pub fn intervals(&self) -> &'static [Interval] {
+        match self {
+            ChordQuality::Major => &[
+                Interval::PERFECT_UNISON,
+                Interval::MAJOR_THIRD,
+                Interval::PERFECT_FIFTH,
+            ],
+            ChordQuality::Minor => &[
+                Interval::PERFECT_UNISON,
+                Interval::MINOR_THIRD,
+                Interval::PERFECT_FIFTH,
+            ],
+            ChordQuality::Diminished => &[
+                Interval::PERFECT_UNISON,
+                Interval::MINOR_THIRD,
+                Interval::DIMINISHED_FIFTH,
+            ],
+            ChordQuality::Augmented => &[
+                Interval::PERFECT_UNISON,
+                Interval::MAJOR_THIRD,
+                Interval::AUGMENTED_FIFTH,
+            ],
+            ChordQuality::Sus2 => &[
+                Interval::PERFECT_UNISON,
+                Interval::MAJOR_SECOND,
+                Interval::PERFECT_FIFTH,
+            ],
+            ChordQuality::Sus4 => &[
+                Interval::PERFECT_UNISON,
+                Interval::PERFECT_FOURTH,
+                Interval::PERFECT_FIFTH,
+            ],
+        }
+    }
+}
+
+/// A spelled interval, stored as a number of perfect fifths plus a number of octaves
+///
+/// Storing intervals on the line of fifths keeps their spelling: an augmented
+/// fourth (six fifths up, three octaves down) and a diminished fifth (six
+/// fifths down, four octaves up) both span six semitones but are different
+/// intervals.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Letter, Accidental, Interval};
+///
+/// let d = NoteName::new(Letter::D, Accidental::Natural);
+/// assert_eq!(d + Interval::MAJOR_THIRD, NoteName::new(Letter::F, Accidental::Sharp));
+/// assert_eq!(Interval::MAJOR_THIRD.semitones(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Interval {
+    fifths: i8,
+    octaves: i8,
+}
+
+/// The direction of an interval, as returned by [`Interval::direction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalDirection {
+    Ascending,
+    Descending,
+    Unison,
+}
+
+impl Interval {
+    pub const PERFECT_UNISON: Interval = Interval::new(0, 0);
+    pub const MINOR_SECOND: Interval = Interval::new(-5, 3);
+    pub const MAJOR_SECOND: Interval = Interval::new(2, -1);
+    pub const AUGMENTED_SECOND: Interval = Interval::new(9, -5);
+    pub const MINOR_THIRD: Interval = Interval::new(-3, 2);
+    pub const MAJOR_THIRD: Interval = Interval::new(4, -2);
+    pub const DIMINISHED_FOURTH: Interval = Interval::new(-8, 5);
+    pub const PERFECT_FOURTH: Interval = Interval::new(-1, 1);
+    pub const AUGMENTED_FOURTH: Interval = Interval::new(6, -3);
+    pub const DIMINISHED_FIFTH: Interval = Interval::new(-6, 4);
+    pub const PERFECT_FIFTH: Interval = Interval::new(1, 0);
+    pub const AUGMENTED_FIFTH: Interval = Interval::new(8, -4);
+    pub const MINOR_SIXTH: Interval = Interval::new(-4, 3);
+    pub const MAJOR_SIXTH: Interval = Interval::new(3, -1);
+    pub const AUGMENTED_SIXTH: Interval = Interval::new(10, -5);
+    pub const DIMINISHED_SEVENTH: Interval = Interval::new(-9, 6);
+    pub const MINOR_SEVENTH: Interval = Interval::new(-2, 2);
+    pub const MAJOR_SEVENTH: Interval = Interval::new(5, -2);
+    pub const PERFECT_OCTAVE: Interval = Interval::new(0, 1);
+    pub const MINOR_NINTH: Interval = Interval::new(-5, 4);
+    pub const MAJOR_NINTH: Interval = Interval::new(2, 0);
+    pub const AUGMENTED_NINTH: Interval = Interval::new(9, -4);
+    pub const PERFECT_ELEVENTH: Interval = Interval::new(-1, 2);
+    pub const AUGMENTED_ELEVENTH: Interval = Interval::new(6, -2);
+    pub const MINOR_THIRTEENTH: Interval = Interval::new(-4, 4);
+    pub const MAJOR_THIRTEENTH: Interval = Interval::new(3, 0);
+
+    pub const fn new(fifths: i8, octaves: i8) -> Self {
+        Interval { fifths, octaves }
+    }
+
+    pub fn fifths(&self) -> i8 {
+        self.fifths
+    }
+
+    pub fn octaves(&self) -> i8 {
+        self.octaves
+    }
+
+    /// Returns the size of the interval in semitones
+    pub const fn semitones(&self) -> i8 {
+        // Summed in i16, since heavily altered intervals overflow the terms
+        (7 * self.fifths as i16 + 12 * self.octaves as i16) as i8
+    }
+
+    /// Returns the number of letter steps the interval spans (a third spans 2)
+    pub fn steps(&self) -> i8 {
+        (4 * self.fifths as i16 + 7 * self.octaves as i16) as i8
+    }
+
+    /// Returns whether the interval goes up or down
+    ///
+    /// The direction follows the letter steps, so a diminished octave is
+    /// ascending even though it is smaller than an augmented seventh. Among
+    /// unisons, the diminished unison counts as descending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::{Interval, IntervalDirection};
+    ///
+    /// let down = Interval::PERFECT_UNISON - Interval::PERFECT_FIFTH;
+    /// assert_eq!(down.direction(), IntervalDirection::Descending);
+    /// assert_eq!(down.to_string(), "-P5");
+    /// ```
+    pub fn direction(&self) -> IntervalDirection {
+        match self.steps() {
+            0 => match self.semitones() {
+                0 => IntervalDirection::Unison,
+                semitones if semitones < 0 => IntervalDirection::Descending,
+                _ => IntervalDirection::Ascending,
+            },
+            steps if steps < 0 => IntervalDirection::Descending,
+            _ => IntervalDirection::Ascending,
+        }
+    }
+
+    /// Reduces the interval to its simple form within one octave
+    ///
+    /// A major ninth becomes a major second and a perfect octave becomes a
+    /// unison; the quality is kept.
+    pub fn simple(&self) -> Interval {
+        let octaves = -(4 * self.fifths as i16).div_euclid(7);
+        Interval::new(self.fifths, octaves as i8)
+    }
+
+    /// Checks if two intervals span the same number of semitones, whatever their spelling
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::Interval;
+    ///
+    /// assert!(Interval::AUGMENTED_FOURTH.is_enharmonic_with(&Interval::DIMINISHED_FIFTH));
+    /// assert!(!Interval::MAJOR_THIRD.is_enharmonic_with(&(Interval::MAJOR_THIRD + Interval::PERFECT_OCTAVE)));
+    /// ```
+    pub fn is_enharmonic_with(&self, other: &Interval) -> bool {
+        self.semitones() == other.semitones()
+    }
+
+    /// Returns the equal-tempered size of the interval in cents
+    pub fn cents(&self) -> f64 {
+        self.semitones() as f64 * 100.0
+    }
+
+    /// Returns the size of the interval in cents when tuned in pure fifths
+    ///
+    /// This is Pythagorean tuning: the interval is built from its stack of
+    /// 3:2 fifths and 2:1 octaves, so a perfect fifth is about 702 cents and a
+    /// major third (four fifths up, two octaves down) about 408 cents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chordy::Interval;
+    ///
+    /// assert_eq!(Interval::PERFECT_FIFTH.cents(), 700.0);
+    /// assert!((Interval::PERFECT_FIFTH.just_cents() - 701.955).abs() < 0.001);
+    /// ```
+    pub fn just_cents(&self) -> f64 {
+        1200.0 * (self.fifths as f64 * 1.5f64.log2() + self.octaves as f64)
+    }
+}
+
+/// Fifths of the major or perfect interval for each generic interval within
+/// an octave (unison, second, ..., seventh)
+const INTERVAL_CLASS_FIFTHS: [i8; 7] = [0, 2, 4, -1, 1, 3, 5];
+
+/// Writes intervals in the usual shorthand: a quality (`P`, `M`, `m`, `A`,
+/// `d`, with `AA`/`dd` for doubly altered intervals) followed by the interval
+/// number. Compound intervals keep counting (`M9`, `P11`), and descending
+/// intervals get a leading `-`.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::Interval;
+///
+/// assert_eq!(Interval::PERFECT_FIFTH.to_string(), "P5");
+/// assert_eq!(Interval::MINOR_SEVENTH.to_string(), "m7");
+/// assert_eq!(Interval::AUGMENTED_ELEVENTH.to_string(), "A11");
+/// ```
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.direction() == IntervalDirection::Descending {
+            let ascending = Interval::new(-self.fifths, -self.octaves);
+            return write!(f, "-{}", ascending);
+        }
+
+        let class = (self.fifths as i16 * 4).rem_euclid(7) as usize;
+        let alteration = (self.fifths as i16 - INTERVAL_CLASS_FIFTHS[class] as i16).div_euclid(7);
+        let perfect = matches!(class, 0 | 3 | 4);
+
+        let quality = match (perfect, alteration) {
+            (true, 0) => "P".to_string(),
+            (false, 0) => "M".to_string(),
+            (false, -1) => "m".to_string(),
+            (_, n) if n > 0 => "A".repeat(n as usize),
+            (true, n) => "d".repeat(n.unsigned_abs() as usize),
+            (false, n) => "d".repeat(n.unsigned_abs() as usize - 1),
+        };
+        write!(f, "{}{}", quality, self.steps() + 1)
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    /// Parses the shorthand written by `Display`, such as `P5`, `m3`, `A4`,
+    /// `dd7`, `M9` or `-P4`
+    ///
+    /// Intervals that can't be stored in an [`Interval`], such as `AAAAA1`,
+    /// are rejected with [`ParseError::InvalidInterval`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidInterval(s.to_string());
+
+        let (descending, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let split = rest
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let (quality, number) = rest.split_at(split);
+        let number: i8 = number.parse().map_err(|_| invalid())?;
+        if number < 1 || quality.is_empty() {
+            return Err(invalid());
+        }
+
+        // Work in i16 so that heavily altered intervals can't overflow
+        let steps = number as i16 - 1;
+        let class = steps.rem_euclid(7) as usize;
+        let perfect = matches!(class, 0 | 3 | 4);
+        let repeated = |c: char| quality.chars().all(|q| q == c);
+        let count = i16::try_from(quality.len()).map_err(|_| invalid())?;
+
+        let alteration = match quality {
+            "P" if perfect => 0,
+            "M" if !perfect => 0,
+            "m" if !perfect => -1,
+            _ if repeated('A') => count,
+            _ if repeated('d') && perfect => -count,
+            _ if repeated('d') => -count - 1,
+            _ => return Err(invalid()),
+        };
+
+        let fifths = alteration
+            .checked_mul(7)
+            .and_then(|a| a.checked_add(INTERVAL_CLASS_FIFTHS[class] as i16))
+            .filter(|f| i8::try_from(*f).is_ok())
+            .ok_or_else(invalid)?;
+        let octaves = (steps - 4 * fifths) / 7;
+
+        // The octaves and the size in semitones have to fit in an i8 as well
+        let fits = |n: i16| i8::try_from(n).is_ok();
+        if !fits(octaves) || !fits(7 * fifths + 12 * octaves) {
+            return Err(invalid());
+        }
+
+        let interval = Interval::new(fifths as i8, octaves as i8);
+        Ok(if descending { -interval } else { interval })
+    }
+}
+
+/// Implements `TryFrom<&str>` and `TryFrom<String>` by delegating to `FromStr`
+macro_rules! impl_try_from_str {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<&str> for $t {
+                type Error = ParseError;
+
+                fn try_from(s: &str) -> Result<Self, Self::Error> {
+                    s.parse()
+                }
+            }
+
+            impl TryFrom<String> for $t {
+                type Error = ParseError;
+
+                fn try_from(s: String) -> Result<Self, Self::Error> {
+                    s.parse()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_str!(NoteName, Pitch, Interval, Chord);
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, other: Interval) -> Interval {
+        Interval::new(self.fifths + other.fifths, self.octaves + other.octaves)
+    }
+}
+
+/// Reverses the direction of an interval, so a rising fifth becomes a falling one
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        Interval::new(-self.fifths, -self.octaves)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: Interval) -> Interval {
+        Interval::new(self.fifths - other.fifths, self.octaves - other.octaves)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScaleType {
     Major,
@@ -265,6 +4333,26 @@ pub enum ScaleType {
     // etc.
 }
 
+impl ScaleType {
+    /// Returns the semitone offset of each scale degree from the tonic
+    #[deprecated(
+        note = "use `ScaleDefinition::from(scale_type).intervals()`, which keeps the spelling"
+    )]
+    pub fn semitones(&self) -> &'static [i8] {
+        match self {
+            ScaleType::Major => &[0, 2, 4, 5, 7, 9, 11],
+            ScaleType::NaturalMinor => &[0, 2, 3, 5, 7, 8, 10],
+            ScaleType::HarmonicMinor => &[0, 2, 3, 5, 7, 8, 11],
+            ScaleType::MelodicMinor => &[0, 2, 3, 5, 7, 9, 11],
+            ScaleType::Dorian => &[0, 2, 3, 5, 7, 9, 10],
+            ScaleType::Phrygian => &[0, 1, 3, 5, 7, 8, 10],
+            ScaleType::Lydian => &[0, 2, 4, 6, 7, 9, 11],
+            ScaleType::Mixolydian => &[0, 2, 4, 5, 7, 9, 10],
+            ScaleType::Locrian => &[0, 1, 3, 5, 6, 8, 10],
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
     Major,
@@ -319,6 +4407,75 @@ pub enum SeventhType {
 
     /// Diminished seventh (diminished triad with diminished 7th)
     Diminished,
+
+    /// Minor-major seventh (minor triad with major 7th)
+    MinorMajor,
+
+    /// Augmented major seventh (augmented triad with major 7th)
+    AugmentedMajor,
+}
+
+impl SeventhType {
+    /// Returns the seventh type spelled out in English ("dominant seventh")
+    pub fn full_name(&self) -> &'static str {
+        match self {
+            SeventhType::Dominant => "dominant seventh",
+            SeventhType::Major => "major seventh",
+            SeventhType::Minor => "minor seventh",
+            SeventhType::HalfDiminished => "half-diminished seventh",
+            SeventhType::Diminished => "diminished seventh",
+            SeventhType::MinorMajor => "minor-major seventh",
+            SeventhType::AugmentedMajor => "augmented major seventh",
+        }
+    }
+
+    /// Returns the intervals of the full seventh chord, including the root
+    pub fn intervals(&self) -> &'static [Interval] {
+        match self {
+            SeventhType::Dominant => &[
+                Interval::PERFECT_UNISON,
+                Interval::MAJOR_THIRD,
+                Interval::PERFECT_FIFTH,
+                Interval::MINOR_SEVENTH,
+            ],
+            SeventhType::Major => &[
+                Interval::PERFECT_UNISON,
+                Interval::MAJOR_THIRD,
+                Interval::PERFECT_FIFTH,
+                Interval::MAJOR_SEVENTH,
+            ],
+            SeventhType::Minor => &[
+                Interval::PERFECT_UNISON,
+                Interval::MINOR_THIRD,
+                Interval::PERFECT_FIFTH,
+                Interval::MINOR_SEVENTH,
+            ],
+            SeventhType::HalfDiminished => &[
+                Interval::PERFECT_UNISON,
+                Interval::MINOR_THIRD,
+                Interval::DIMINISHED_FIFTH,
+                Interval::MINOR_SEVENTH,
+            ],
+            SeventhType::Diminished => &[
+                Interval::PERFECT_UNISON,
+                Interval::MINOR_THIRD,
+                Interval::DIMINISHED_FIFTH,
+                Interval::DIMINISHED_SEVENTH,
+            ],
+            SeventhType::MinorMajor => &[
+                Interval::PERFECT_UNISON,
+                Interval::MINOR_THIRD,
+                Interval::PERFECT_FIFTH,
+                Interval::MAJOR_SEVENTH,
+            ],
+            SeventhType::AugmentedMajor => &[
+                Interval::PERFECT_UNISON,
+                Interval::MAJOR_THIRD,
+                Interval::AUGMENTED_FIFTH,
+                Interval::MAJOR_SEVENTH,
+            ],
+        }
+    }
 }
 
 /// Types of ninth extensions
@@ -409,3 +4566,12 @@ pub enum OmittedNote {
     /// Omitted 5th
     No5,
 }
+
+impl fmt::Display for OmittedNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OmittedNote::No3 => write!(f, "no3"),
+            OmittedNote::No5 => write!(f, "no5"),
+        }
+    }
+}
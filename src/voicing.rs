@@ -0,0 +1,132 @@
+use crate::types::{Chord, NoteName, Pitch};
+
+/// Measures how far the voices move between two voicings, in semitones
+///
+/// Both voicings are read from the bottom up and matched without crossing
+/// voices. When the sizes differ, voices may split or merge: a voice of the
+/// smaller chord is paired with several neighbouring voices of the larger
+/// one. The cost is the smallest total distance of any such matching, so
+/// equal voicings cost 0 and moving one voice by a step costs 1 or 2.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Pitch};
+/// use chordy::voicing::voice_leading_cost;
+///
+/// let pitches = |notes: [NoteName; 3]| notes.map(|n| Pitch::new(n, 4));
+/// let c = pitches([NoteName::C, NoteName::E, NoteName::G]);
+/// let f = pitches([NoteName::C, NoteName::F, NoteName::A]);
+/// assert_eq!(voice_leading_cost(&c, &f), 3); // C stays, E→F, G→A
+/// ```
+pub fn voice_leading_cost(from: &[Pitch], to: &[Pitch]) -> u32 {
+    if from.is_empty() || to.is_empty() {
+        return 0;
+    }
+
+    let sorted = |pitches: &[Pitch]| {
+        let mut semitones: Vec<i16> = pitches.iter().map(|p| p.midi_number()).collect();
+        semitones.sort_unstable();
+        semitones
+    };
+    let (from, to) = (sorted(from), sorted(to));
+
+    // cost[i][j]: cheapest matching of from[..=i] and to[..=j] that pairs from[i] with to[j]
+    let mut cost = vec![vec![u32::MAX; to.len()]; from.len()];
+    for i in 0..from.len() {
+        for j in 0..to.len() {
+            let step = from[i].abs_diff(to[j]) as u32;
+            let previous = match (i, j) {
+                (0, 0) => 0,
+                (0, _) => cost[0][j - 1],
+                (_, 0) => cost[i - 1][0],
+                _ => cost[i - 1][j - 1].min(cost[i - 1][j]).min(cost[i][j - 1]),
+            };
+            cost[i][j] = previous + step;
+        }
+    }
+    cost[from.len() - 1][to.len() - 1]
+}
+
+/// Voices a progression so that each chord moves as little as possible from the last
+///
+/// The first chord is voiced in close position from `start_octave`. Every
+/// later chord tone is placed near one of the previous chord's pitches, and
+/// of all such voicings the one with the lowest [`voice_leading_cost`] is
+/// kept, which holds common tones and moves the other voices by the
+/// smallest steps. Each voicing has one pitch per chord tone, listed from
+/// the bottom up, so chords of different sizes are handled by splitting or
+/// merging voices.
+///
+/// # Examples
+///
+/// ```
+/// use chordy::{NoteName, Chord};
+/// use chordy::voicing::smooth_voicing;
+///
+/// let progression = [Chord::major(NoteName::C), Chord::major(NoteName::F)];
+/// let voicings = smooth_voicing(&progression, 4);
+/// let names: Vec<String> = voicings[1].iter().map(|p| p.to_string()).collect();
+/// assert_eq!(names, ["C4", "F4", "A4"]);
+/// ```
+pub fn smooth_voicing(progression: &[Chord], start_octave: i8) -> Vec<Vec<Pitch>> {
+    let mut voicings: Vec<Vec<Pitch>> = Vec::with_capacity(progression.len());
+
+    for chord in progression {
+        let voicing = match voicings.last() {
+            None => chord.voice(start_octave),
+            Some(previous) if previous.is_empty() => chord.voice(start_octave),
+            Some(previous) => closest_voicing(previous, &chord.notes()),
+        };
+        voicings.push(voicing);
+    }
+    voicings
+}
+
+/// Finds the voicing of `notes` with the lowest voice-leading cost from `previous`
+fn closest_voicing(previous: &[Pitch], notes: &[NoteName]) -> Vec<Pitch> {
+    // Each tone may sit next to any of the previous pitches
+    let candidates: Vec<Vec<Pitch>> = notes
+        .iter()
+        .map(|&note| {
+            let mut placements: Vec<Pitch> =
+                previous.iter().map(|&p| nearest_pitch(note, p)).collect();
+            placements.sort();
+            placements.dedup();
+            placements
+        })
+        .collect();
+
+    let mut best: Option<(u32, Vec<Pitch>)> = None;
+    let mut choice = vec![0; notes.len()];
+    loop {
+        let mut voicing: Vec<Pitch> = choice
+            .iter()
+            .enumerate()
+            .map(|(tone, &i)| candidates[tone][i])
+            .collect();
+        voicing.sort();
+
+        let cost = voice_leading_cost(previous, &voicing);
+        if best.as_ref().is_none_or(|(best_cost, _)| cost < *best_cost) {
+            best = Some((cost, voicing));
+        }
+
+        // Advance to the next combination of placements
+        let Some(tone) = (0..notes.len()).find(|&t| choice[t] + 1 < candidates[t].len()) else {
+            break;
+        };
+        choice[tone] += 1;
+        choice[..tone].fill(0);
+    }
+
+    best.map(|(_, voicing)| voicing).unwrap_or_default()
+}
+
+/// Places a note in the octave that brings it closest to `target`, preferring below on a tie
+fn nearest_pitch(note: NoteName, target: Pitch) -> Pitch {
+    let semitones = target.midi_number();
+    let up = (note.base_midi_number() as i16 - semitones).rem_euclid(12);
+    let offset = if up < 6 { up } else { up - 12 };
+    Pitch::from_midi_number(note, semitones + offset)
+}
@@ -0,0 +1,104 @@
+use chordy::analysis::{Cadence, detect_cadence};
+use chordy::scales;
+use chordy::types::*;
+
+fn natural(letter: Letter) -> NoteName {
+    NoteName::new(letter, Accidental::Natural)
+}
+
+fn c_major() -> Scale {
+    Scale::new(natural(Letter::C), ScaleType::Major)
+}
+
+#[test]
+fn test_authentic_cadence() {
+    let chords = [
+        Chord::major(natural(Letter::F)),
+        Chord::major(natural(Letter::G)),
+        Chord::major(natural(Letter::C)),
+    ];
+    assert_eq!(
+        detect_cadence(&chords, &c_major()),
+        Some(Cadence::Authentic)
+    );
+}
+
+#[test]
+fn test_plagal_cadence() {
+    let chords = [
+        Chord::major(natural(Letter::F)),
+        Chord::major(natural(Letter::C)),
+    ];
+    assert_eq!(detect_cadence(&chords, &c_major()), Some(Cadence::Plagal));
+}
+
+#[test]
+fn test_half_cadence() {
+    let chords = [
+        Chord::minor(natural(Letter::D)),
+        Chord::seventh(natural(Letter::G), SeventhType::Dominant),
+    ];
+    assert_eq!(detect_cadence(&chords, &c_major()), Some(Cadence::Half));
+}
+
+#[test]
+fn test_ending_on_leading_tone_chord_is_not_a_cadence() {
+    // vii° has a dominant function, but a half cadence must end on V
+    let chords = [
+        Chord::major(natural(Letter::C)),
+        Chord::diminished(natural(Letter::B)),
+    ];
+    assert_eq!(detect_cadence(&chords, &c_major()), None);
+}
+
+#[test]
+fn test_deceptive_cadence() {
+    let chords = [
+        Chord::seventh(natural(Letter::G), SeventhType::Dominant),
+        Chord::minor(natural(Letter::A)),
+    ];
+    assert_eq!(
+        detect_cadence(&chords, &c_major()),
+        Some(Cadence::Deceptive)
+    );
+}
+
+#[test]
+fn test_cadences_in_a_pentatonic_key() {
+    // A is the fifth note of the pentatonic scale but still vi, not V
+    let pentatonic = Scale::new(natural(Letter::C), scales::MAJOR_PENTATONIC);
+    let g = Chord::major(natural(Letter::G));
+    let a_minor = Chord::minor(natural(Letter::A));
+
+    let chords = [Chord::major(natural(Letter::C)), g.clone()];
+    assert_eq!(detect_cadence(&chords, &pentatonic), Some(Cadence::Half));
+    let chords = [g, a_minor.clone()];
+    assert_eq!(
+        detect_cadence(&chords, &pentatonic),
+        Some(Cadence::Deceptive)
+    );
+    let chords = [Chord::major(natural(Letter::C)), a_minor];
+    assert_eq!(detect_cadence(&chords, &pentatonic), None);
+}
+
+#[test]
+fn test_no_cadence() {
+    let scale = c_major();
+    assert_eq!(detect_cadence(&[], &scale), None);
+    assert_eq!(
+        detect_cadence(&[Chord::major(natural(Letter::C))], &scale),
+        None
+    );
+
+    let chords = [
+        Chord::major(natural(Letter::C)),
+        Chord::major(natural(Letter::F)),
+    ];
+    assert_eq!(detect_cadence(&chords, &scale), None);
+
+    let chords = [
+        Chord::major(natural(Letter::G)),
+        Chord::major(NoteName::new(Letter::E, Accidental::Flat)),
+    ];
+    assert_eq!(detect_cadence(&chords, &scale), None);
+}
@@ -0,0 +1,50 @@
+use chordy::analysis::infer_key_from_progression;
+use chordy::types::*;
+
+#[test]
+fn test_infer_c_major() {
+    let chords = [
+        Chord::major(NoteName::C),
+        Chord::major(NoteName::F),
+        Chord::major(NoteName::G),
+        Chord::major(NoteName::C),
+    ];
+    assert_eq!(
+        infer_key_from_progression(&chords),
+        Some(Scale::new(NoteName::C, ScaleType::Major))
+    );
+}
+
+#[test]
+fn test_infer_a_minor() {
+    // The major dominant is outside A natural minor, but the tonic chord decides
+    let chords = [
+        Chord::minor(NoteName::A),
+        Chord::minor(NoteName::D),
+        Chord::major(NoteName::E),
+        Chord::minor(NoteName::A),
+    ];
+    assert_eq!(
+        infer_key_from_progression(&chords),
+        Some(Scale::new(NoteName::A, ScaleType::NaturalMinor))
+    );
+}
+
+#[test]
+fn test_infer_flat_key() {
+    let b_flat = NoteName::new(Letter::B, Accidental::Flat);
+    let chords = [
+        Chord::seventh(NoteName::C, SeventhType::Minor),
+        Chord::seventh(NoteName::F, SeventhType::Dominant),
+        Chord::seventh(b_flat, SeventhType::Major),
+    ];
+    assert_eq!(
+        infer_key_from_progression(&chords),
+        Some(Scale::new(b_flat, ScaleType::Major))
+    );
+}
+
+#[test]
+fn test_infer_empty_progression() {
+    assert_eq!(infer_key_from_progression(&[]), None);
+}
@@ -0,0 +1,35 @@
+use chordy::analysis::is_chromatic_mediant;
+use chordy::types::*;
+
+fn flat(letter: Letter) -> NoteName {
+    NoteName::new(letter, Accidental::Flat)
+}
+
+#[test]
+fn test_classic_chromatic_mediants() {
+    let c = Chord::major(NoteName::C);
+    let pairs = [
+        Chord::major(flat(Letter::A)),
+        Chord::major(NoteName::E),
+        Chord::major(NoteName::A),
+        Chord::major(flat(Letter::E)),
+    ];
+    for other in pairs {
+        assert!(is_chromatic_mediant(&c, &other), "C and {}", other);
+        assert!(is_chromatic_mediant(&other, &c), "{} and C", other);
+    }
+
+    let c_minor = Chord::minor(NoteName::C);
+    assert!(is_chromatic_mediant(&c_minor, &Chord::minor(NoteName::A)));
+}
+
+#[test]
+fn test_diatonic_mediants_are_not_chromatic() {
+    let c = Chord::major(NoteName::C);
+    assert!(!is_chromatic_mediant(&c, &Chord::minor(NoteName::A)));
+    assert!(!is_chromatic_mediant(&c, &Chord::minor(NoteName::E)));
+
+    // Roots a fifth apart, or no shared tone at all
+    assert!(!is_chromatic_mediant(&c, &Chord::major(NoteName::G)));
+    assert!(!is_chromatic_mediant(&c, &Chord::minor(flat(Letter::A))));
+}
@@ -0,0 +1,5 @@
+mod cadence_tests;
+mod key_inference_tests;
+mod mediant_tests;
+mod modulation_tests;
+mod root_motion_tests;
@@ -0,0 +1,61 @@
+use chordy::analysis::detect_modulation;
+use chordy::types::*;
+
+fn natural(letter: Letter) -> NoteName {
+    NoteName::new(letter, Accidental::Natural)
+}
+
+#[test]
+fn test_modulation_from_c_to_g() {
+    let chords = [
+        Chord::major(natural(Letter::C)),
+        Chord::minor(natural(Letter::A)),
+        Chord::major(natural(Letter::F)),
+        Chord::major(natural(Letter::G)),
+        Chord::major(natural(Letter::C)),
+        Chord::major(natural(Letter::D)),
+        Chord::major(natural(Letter::G)),
+        Chord::minor(natural(Letter::E)),
+        Chord::seventh(natural(Letter::D), SeventhType::Dominant),
+        Chord::major(natural(Letter::G)),
+    ];
+
+    assert_eq!(
+        detect_modulation(&chords),
+        vec![
+            (0, Key::major(natural(Letter::C))),
+            (5, Key::major(natural(Letter::G))),
+        ]
+    );
+}
+
+#[test]
+fn test_no_modulation() {
+    let chords = [
+        Chord::major(natural(Letter::C)),
+        Chord::major(natural(Letter::F)),
+        Chord::seventh(natural(Letter::G), SeventhType::Dominant),
+        Chord::major(natural(Letter::C)),
+        Chord::minor(natural(Letter::A)),
+        Chord::minor(natural(Letter::D)),
+    ];
+    assert_eq!(
+        detect_modulation(&chords),
+        vec![(0, Key::major(natural(Letter::C)))]
+    );
+}
+
+#[test]
+fn test_short_and_empty_progressions() {
+    assert!(detect_modulation(&[]).is_empty());
+
+    let chords = [
+        Chord::minor(natural(Letter::A)),
+        Chord::minor(natural(Letter::D)),
+        Chord::minor(natural(Letter::A)),
+    ];
+    assert_eq!(
+        detect_modulation(&chords),
+        vec![(0, Key::minor(natural(Letter::A)))]
+    );
+}
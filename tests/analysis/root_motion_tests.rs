@@ -0,0 +1,45 @@
+use chordy::analysis::root_motions;
+use chordy::types::*;
+
+#[test]
+fn test_circle_of_fifths_root_motion() {
+    let roots = [
+        NoteName::E,
+        NoteName::A,
+        NoteName::D,
+        NoteName::G,
+        NoteName::C,
+        NoteName::F,
+    ];
+    let chords: Vec<Chord> = roots
+        .iter()
+        .map(|&root| Chord::seventh(root, SeventhType::Dominant))
+        .collect();
+    let motions = root_motions(&chords);
+    assert_eq!(motions, vec![Interval::PERFECT_FOURTH; 5]);
+}
+
+#[test]
+fn test_chromatic_mediant_root_motion() {
+    let a_flat = NoteName::new(Letter::A, Accidental::Flat);
+    let chords = [
+        Chord::major(NoteName::C),
+        Chord::major(a_flat),
+        Chord::major(NoteName::C),
+        Chord::major(NoteName::E),
+    ];
+    assert_eq!(
+        root_motions(&chords),
+        [
+            Interval::MINOR_SIXTH,
+            Interval::MAJOR_THIRD,
+            Interval::MAJOR_THIRD
+        ]
+    );
+}
+
+#[test]
+fn test_root_motions_short_progressions() {
+    assert!(root_motions(&[]).is_empty());
+    assert!(root_motions(&[Chord::major(NoteName::C)]).is_empty());
+}
@@ -11,3 +11,15 @@ fn test_invalid_note_name_error() {
     let err = ParseError::InvalidNoteName("H".to_string());
     assert_eq!(err.to_string(), "Invalid note name: 'H'");
 }
+
+#[test]
+fn test_invalid_interval_error() {
+    let err = ParseError::InvalidInterval("P3".to_string());
+    assert_eq!(err.to_string(), "Invalid interval: 'P3'");
+}
+
+#[test]
+fn test_invalid_roman_numeral_error() {
+    let err = ParseError::InvalidRomanNumeral("IIX".to_string());
+    assert_eq!(err.to_string(), "Invalid roman numeral: 'IIX'");
+}
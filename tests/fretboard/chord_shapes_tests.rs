@@ -0,0 +1,68 @@
+use chordy::fretboard::{STANDARD_TUNING, chord_shapes};
+use chordy::types::*;
+
+#[test]
+fn test_open_c_major_shape() {
+    let shapes = chord_shapes(&Chord::major(NoteName::C), &STANDARD_TUNING, 5);
+    let open_c = vec![None, Some(3), Some(2), Some(0), Some(1), Some(0)];
+    assert!(shapes.contains(&open_c), "{:?}", shapes);
+}
+
+#[test]
+fn test_open_e_minor_shape() {
+    let shapes = chord_shapes(&Chord::minor(NoteName::E), &STANDARD_TUNING, 5);
+    assert_eq!(
+        shapes[0],
+        [Some(0), Some(2), Some(2), Some(0), Some(0), Some(0)]
+    );
+}
+
+#[test]
+fn test_shapes_are_playable() {
+    let g7 = Chord::seventh(NoteName::G, SeventhType::Dominant);
+    let shapes = chord_shapes(&g7, &STANDARD_TUNING, 12);
+    assert!(!shapes.is_empty());
+
+    for shape in &shapes {
+        assert_eq!(shape.len(), STANDARD_TUNING.len());
+
+        let fretted: Vec<u8> = shape.iter().flatten().copied().filter(|&f| f > 0).collect();
+        if let (Some(low), Some(high)) = (fretted.iter().min(), fretted.iter().max()) {
+            assert!(high - low <= 3, "{:?}", shape);
+        }
+
+        // The bass is the root
+        let (string, fret) = shape
+            .iter()
+            .enumerate()
+            .find_map(|(i, f)| f.map(|f| (i, f)))
+            .unwrap();
+        assert_eq!((STANDARD_TUNING[string].pitch_class() + fret) % 12, 7);
+    }
+}
+
+#[test]
+fn test_barre_shape() {
+    // F major has no open shape, but the E-shape barre fits
+    let shapes = chord_shapes(&Chord::major(NoteName::F), &STANDARD_TUNING, 5);
+    let barre = vec![Some(1), Some(3), Some(3), Some(2), Some(1), Some(1)];
+    assert!(shapes.contains(&barre), "{:?}", shapes);
+}
+
+#[test]
+fn test_highest_frets() {
+    // An open B string at fret 255 would overflow a u8 sum
+    let shapes = chord_shapes(&Chord::major(NoteName::E), &STANDARD_TUNING, u8::MAX);
+    let high = shapes
+        .iter()
+        .find(|shape| shape.iter().flatten().any(|&f| f > 240))
+        .expect("a shape near the top fret");
+    for (open, fret) in STANDARD_TUNING
+        .iter()
+        .zip(high)
+        .filter_map(|(o, f)| f.map(|f| (o, f)))
+    {
+        let pitch_class = (u16::from(open.pitch_class()) + u16::from(fret)) % 12;
+        assert!([4, 8, 11].contains(&pitch_class), "{:?}", high);
+    }
+}
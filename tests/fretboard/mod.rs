@@ -0,0 +1 @@
+mod chord_shapes_tests;
@@ -1,3 +1,10 @@
+mod analysis;
 mod error;
+mod fretboard;
+mod macros;
+mod scales;
 mod symbols;
+mod transposition;
+mod tuning;
 mod types;
+mod voicing;
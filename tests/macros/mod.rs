@@ -0,0 +1,2 @@
+mod note_macro_tests;
+mod pitch_macro_tests;
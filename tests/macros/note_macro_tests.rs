@@ -0,0 +1,48 @@
+use chordy::macros::is_valid_note;
+use chordy::note;
+use chordy::types::{Accidental, Letter, NoteName};
+
+#[test]
+fn test_note_macro() {
+    assert_eq!(note!("C"), NoteName::new(Letter::C, Accidental::Natural));
+    assert_eq!(note!("F#"), NoteName::new(Letter::F, Accidental::Sharp));
+    assert_eq!(note!("E♭"), NoteName::new(Letter::E, Accidental::Flat));
+    assert_eq!(
+        note!("Bbb"),
+        NoteName::new(Letter::B, Accidental::DoubleFlat)
+    );
+    assert_eq!(
+        note!("G𝄪"),
+        NoteName::new(Letter::G, Accidental::DoubleSharp)
+    );
+}
+
+#[test]
+fn test_note_macro_natural_sign() {
+    assert_eq!(note!("Cn"), note!("C"));
+    assert_eq!(note!("C♮"), note!("C"));
+    assert_eq!("Cn".parse::<NoteName>(), Ok(note!("C")));
+    assert_eq!("A♮".parse::<NoteName>(), Ok(note!("A")));
+}
+
+#[test]
+fn test_whitespace_is_rejected() {
+    for name in ["C ", " C", "C #", "\tD", ""] {
+        assert!(!is_valid_note(name), "{:?}", name);
+        assert!(name.parse::<NoteName>().is_err(), "{:?}", name);
+    }
+}
+
+#[test]
+fn test_macro_and_from_str_agree() {
+    for name in [
+        "C", "Cn", "C♮", "Db", "D♭", "D#", "Dbb", "D##", "D𝄫", "D𝄪", "H", "c", "Cx",
+    ] {
+        assert_eq!(
+            is_valid_note(name),
+            name.parse::<NoteName>().is_ok(),
+            "{}",
+            name
+        );
+    }
+}
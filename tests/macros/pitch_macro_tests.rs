@@ -0,0 +1,29 @@
+use chordy::macros::parse_pitch;
+use chordy::types::{Accidental, Letter, NoteName, Pitch};
+use chordy::{note, pitch};
+
+#[test]
+fn test_pitch_macro() {
+    assert_eq!(pitch!("C4"), Pitch::new(NoteName::C, 4));
+    assert_eq!(
+        pitch!("F#3"),
+        Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 3)
+    );
+    assert_eq!(pitch!("E♭-1"), Pitch::new(note!("Eb"), -1));
+    assert_eq!(pitch!("G𝄪10"), Pitch::new(note!("G##"), 10));
+}
+
+#[test]
+fn test_parse_pitch_rejects_invalid() {
+    for name in ["C", "4", "-1", "C-", "C 4", "H4", "C--1", "C128", "C-129"] {
+        assert_eq!(parse_pitch(name), None, "{:?}", name);
+    }
+    assert_eq!(parse_pitch("C-128"), Some(Pitch::new(NoteName::C, -128)));
+}
+
+#[test]
+fn test_macro_and_from_str_agree() {
+    for name in ["A4", "Bb-1", "C#5", "D♭0", "Cn4"] {
+        assert_eq!(parse_pitch(name), name.parse::<Pitch>().ok(), "{}", name);
+    }
+}
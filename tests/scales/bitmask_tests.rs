@@ -0,0 +1,100 @@
+use chordy::Interval;
+use chordy::scales::{self, ScaleBitmask};
+
+#[test]
+fn test_bitmask_from_intervals() {
+    assert_eq!(scales::IONIAN.bitmask().bits(), 0b1010_1011_0101);
+    assert_eq!(scales::AEOLIAN.bitmask().bits(), 0b0101_1010_1101);
+
+    // Compound intervals fold into the octave
+    let mask = ScaleBitmask::from_intervals(&[
+        Interval::PERFECT_UNISON,
+        Interval::MAJOR_SECOND + Interval::PERFECT_OCTAVE,
+    ]);
+    assert_eq!(mask, ScaleBitmask::new(0b0101));
+}
+
+#[test]
+fn test_bitmask_contains() {
+    let mask = scales::IONIAN.bitmask();
+    assert!(mask.contains(0));
+    assert!(mask.contains(11));
+    assert!(!mask.contains(1));
+    assert!(!mask.contains(6));
+}
+
+#[test]
+fn test_matching_ionian() {
+    let mask = ScaleBitmask::from_intervals(scales::IONIAN.intervals());
+    assert_eq!(scales::matching(mask), vec![scales::IONIAN]);
+}
+
+#[test]
+fn test_matching_nothing() {
+    let chromatic = ScaleBitmask::new(0x0FFF);
+    assert!(scales::matching(chromatic).is_empty());
+}
+
+#[test]
+fn test_bitmask_rotate() {
+    let ionian = scales::IONIAN.bitmask();
+    assert_eq!(ionian.rotate(0), ionian);
+    assert_eq!(ionian.rotate(2), scales::DORIAN.bitmask());
+    assert_eq!(ionian.rotate(9), scales::AEOLIAN.bitmask());
+    assert_eq!(ionian.rotate(14), ionian.rotate(2));
+
+    let single = ScaleBitmask::new(0b0000_0001_0000);
+    assert_eq!(single.rotate(4), ScaleBitmask::new(1));
+    assert_eq!(single.rotate(5), ScaleBitmask::new(0b1000_0000_0000));
+}
+
+#[test]
+fn test_bitmask_rotations() {
+    let ionian = scales::IONIAN.bitmask();
+    let rotations: Vec<ScaleBitmask> = ionian.rotations().collect();
+    assert_eq!(rotations.len(), 12);
+    assert_eq!(rotations[0], ionian);
+
+    // The rotations onto scale degrees are exactly the seven diatonic modes
+    let modes: Vec<ScaleBitmask> = rotations
+        .iter()
+        .copied()
+        .filter(|mask| mask.contains(0))
+        .collect();
+    assert_eq!(modes.len(), 7);
+    for mode in scales::DIATONIC_MODES {
+        assert!(modes.contains(&mode.bitmask()), "{}", mode.name());
+    }
+
+    // The whole-tone scale only has two distinct rotations
+    let whole_tone = scales::WHOLE_TONE.bitmask();
+    assert!(
+        whole_tone
+            .rotations()
+            .all(|mask| mask == whole_tone || mask == whole_tone.rotate(1))
+    );
+}
+
+#[test]
+fn test_bitmask_note_count() {
+    assert_eq!(scales::IONIAN.bitmask().note_count(), 7);
+    assert_eq!(scales::MAJOR_PENTATONIC.bitmask().note_count(), 5);
+    assert_eq!(scales::WHOLE_TONE.bitmask().note_count(), 6);
+    assert_eq!(ScaleBitmask::new(0).note_count(), 0);
+    assert_eq!(ScaleBitmask::new(0xFFFF).note_count(), 12);
+}
+
+#[test]
+fn test_bitmask_is_subset_of() {
+    use chordy::{Chord, HasIntervals, NoteName};
+
+    let ionian = scales::IONIAN.bitmask();
+    let c_major = ScaleBitmask::from_intervals(Chord::major(NoteName::C).intervals());
+    assert!(c_major.is_subset_of(&ionian));
+    assert!(scales::MAJOR_PENTATONIC.bitmask().is_subset_of(&ionian));
+    assert!(ionian.is_subset_of(&ionian));
+    assert!(!ionian.is_subset_of(&scales::MAJOR_PENTATONIC.bitmask()));
+
+    let c_minor = ScaleBitmask::from_intervals(Chord::minor(NoteName::C).intervals());
+    assert!(!c_minor.is_subset_of(&ionian));
+}
@@ -0,0 +1,2 @@
+mod bitmask_tests;
+mod registry_tests;
@@ -0,0 +1,112 @@
+use chordy::scales::{self, ScaleDefinition};
+use chordy::types::*;
+
+#[test]
+fn test_by_name_case_insensitive() {
+    assert_eq!(scales::by_name("dorian"), Some(scales::DORIAN));
+    assert_eq!(scales::by_name("Dorian"), Some(scales::DORIAN));
+    assert_eq!(
+        scales::by_name("HARMONIC MINOR"),
+        Some(scales::HARMONIC_MINOR)
+    );
+}
+
+#[test]
+fn test_by_name_aliases() {
+    assert_eq!(scales::by_name("major"), Some(scales::IONIAN));
+    assert_eq!(scales::by_name("minor"), Some(scales::AEOLIAN));
+}
+
+#[test]
+fn test_by_name_unknown() {
+    assert_eq!(scales::by_name("bogus"), None);
+    assert_eq!(scales::by_name(""), None);
+}
+
+#[test]
+fn test_registry_names_resolve() {
+    for definition in scales::REGISTRY {
+        assert_eq!(scales::by_name(definition.name()), Some(definition.clone()));
+    }
+}
+
+#[test]
+fn test_scale_type_definitions() {
+    assert_eq!(ScaleDefinition::from(ScaleType::Major), scales::IONIAN);
+    assert_eq!(
+        ScaleDefinition::from(ScaleType::NaturalMinor),
+        scales::AEOLIAN
+    );
+
+    let d = NoteName::new(Letter::D, Accidental::Natural);
+    assert_eq!(
+        Scale::new(d, scales::DORIAN),
+        Scale::new(d, ScaleType::Dorian)
+    );
+}
+
+#[test]
+fn test_registry_contains_every_mode() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    for definition in scales::REGISTRY {
+        let scale = Scale::new(c, definition.clone());
+        for n in 0..definition.intervals().len() {
+            let mode = scale.inverted(n);
+            assert_eq!(
+                mode.inverted(definition.intervals().len() - n).definition(),
+                definition,
+                "{} rotated by {}",
+                definition.name(),
+                n
+            );
+        }
+    }
+}
+
+#[test]
+fn test_scale_definition_from_str() {
+    assert_eq!("Dorian".parse(), Ok(scales::DORIAN));
+    assert_eq!("major".parse(), Ok(scales::IONIAN));
+    assert_eq!("whole tone".parse(), Ok(scales::WHOLE_TONE));
+    assert_eq!(
+        "bogus".parse::<ScaleDefinition>(),
+        Err(chordy::error::ParseError::InvalidScaleType(
+            "bogus".to_string()
+        ))
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_scale_type_semitones_match_definitions() {
+    let types = [
+        ScaleType::Major,
+        ScaleType::NaturalMinor,
+        ScaleType::HarmonicMinor,
+        ScaleType::MelodicMinor,
+        ScaleType::Dorian,
+        ScaleType::Phrygian,
+        ScaleType::Lydian,
+        ScaleType::Mixolydian,
+        ScaleType::Locrian,
+    ];
+    for scale_type in types {
+        let semitones: Vec<i8> = ScaleDefinition::from(scale_type)
+            .intervals()
+            .iter()
+            .map(|i| i.semitones())
+            .collect();
+        assert_eq!(scale_type.semitones(), semitones, "{:?}", scale_type);
+    }
+}
+
+#[test]
+fn test_matching_searches_the_whole_registry() {
+    for definition in scales::REGISTRY {
+        assert!(
+            scales::matching(definition.bitmask()).contains(definition),
+            "{}",
+            definition.name()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use chordy::types::{Accidental, Letter, NoteName};
+use chordy::types::{Accidental, Letter, NoteName, Pitch};
 
 #[test]
 fn test_note_display() {
@@ -23,3 +23,32 @@ fn test_accidental_display() {
     assert_eq!(Accidental::Flat.to_string(), "♭");
     assert_eq!(Accidental::DoubleSharp.to_string(), "𝄪");
 }
+
+#[test]
+fn test_accidental_runtime_styles() {
+    assert_eq!(Accidental::Sharp.to_string_ascii(), "#");
+    assert_eq!(Accidental::Sharp.to_string_unicode(), "♯");
+    assert_eq!(Accidental::DoubleFlat.to_string_ascii(), "bb");
+    assert_eq!(Accidental::DoubleFlat.to_string_unicode(), "𝄫");
+    assert_eq!(Accidental::Natural.to_string_ascii(), "n");
+    assert_eq!(Accidental::Natural.to_string_unicode(), "♮");
+}
+
+#[test]
+fn test_note_and_pitch_runtime_styles() {
+    let f_sharp = NoteName::new(Letter::F, Accidental::Sharp);
+    assert_eq!(f_sharp.to_string_ascii(), "F#");
+    assert_eq!(f_sharp.to_string_unicode(), "F♯");
+
+    let b_flat = NoteName::new(Letter::B, Accidental::Flat);
+    assert_eq!(b_flat.to_string_ascii(), "Bb");
+    assert_eq!(b_flat.to_string_unicode(), "B♭");
+
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    assert_eq!(c.to_string_ascii(), "C");
+    assert_eq!(c.to_string_unicode(), "C");
+
+    let pitch = Pitch::new(NoteName::new(Letter::E, Accidental::Flat), 3);
+    assert_eq!(pitch.to_string_ascii(), "Eb3");
+    assert_eq!(pitch.to_string_unicode(), "E♭3");
+}
@@ -0,0 +1,68 @@
+use chordy::transposition::transpose_all;
+use chordy::types::*;
+
+fn c_major_scale() -> Vec<Pitch> {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    Scale::new(c4.name(), ScaleType::Major)
+        .pitches(4, 1)
+        .into_iter()
+        .take(7)
+        .collect()
+}
+
+#[test]
+fn test_transpose_all_uses_flats_consistently() {
+    let transposed = transpose_all(&c_major_scale(), 3);
+    let names: Vec<NoteName> = transposed.iter().map(|p| p.name()).collect();
+    assert_eq!(
+        names,
+        vec![
+            NoteName::new(Letter::E, Accidental::Flat),
+            NoteName::new(Letter::F, Accidental::Natural),
+            NoteName::new(Letter::G, Accidental::Natural),
+            NoteName::new(Letter::A, Accidental::Flat),
+            NoteName::new(Letter::B, Accidental::Flat),
+            NoteName::new(Letter::C, Accidental::Natural),
+            NoteName::new(Letter::D, Accidental::Natural),
+        ]
+    );
+    assert!(
+        transposed
+            .iter()
+            .all(|p| p.name().accidental() != Accidental::Sharp)
+    );
+    assert_eq!(transposed[0].midi_number(), 63);
+    assert_eq!(transposed[6].midi_number(), 74);
+}
+
+#[test]
+fn test_transpose_all_uses_sharps_consistently() {
+    let transposed = transpose_all(&c_major_scale(), 2);
+    let names: Vec<NoteName> = transposed.iter().map(|p| p.name()).collect();
+    assert_eq!(
+        names,
+        vec![
+            NoteName::new(Letter::D, Accidental::Natural),
+            NoteName::new(Letter::E, Accidental::Natural),
+            NoteName::new(Letter::F, Accidental::Sharp),
+            NoteName::new(Letter::G, Accidental::Natural),
+            NoteName::new(Letter::A, Accidental::Natural),
+            NoteName::new(Letter::B, Accidental::Natural),
+            NoteName::new(Letter::C, Accidental::Sharp),
+        ]
+    );
+}
+
+#[test]
+fn test_transpose_all_down() {
+    let transposed = transpose_all(&c_major_scale(), -1);
+    assert_eq!(
+        transposed[0],
+        Pitch::new(NoteName::new(Letter::B, Accidental::Natural), 3)
+    );
+    assert_eq!(
+        transposed[1].name(),
+        NoteName::new(Letter::C, Accidental::Sharp)
+    );
+    assert!(transpose_all(&[], 5).is_empty());
+}
@@ -0,0 +1,95 @@
+use chordy::transposition::{ChromaticTransposer, Transposer};
+use chordy::types::*;
+
+#[test]
+fn test_transpose_keeps_naturals() {
+    let e4 = Pitch::new(NoteName::new(Letter::E, Accidental::Natural), 4);
+    let f4 = Pitch::new(NoteName::new(Letter::F, Accidental::Natural), 4);
+    assert_eq!(ChromaticTransposer.transpose(e4, 1), f4);
+
+    let b3 = Pitch::new(NoteName::new(Letter::B, Accidental::Natural), 3);
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    assert_eq!(ChromaticTransposer.transpose(b3, 1), c4);
+    assert_eq!(ChromaticTransposer.transpose(c4, -1), b3);
+}
+
+#[test]
+fn test_transpose_follows_source_accidental() {
+    let b_flat = Pitch::new(NoteName::new(Letter::B, Accidental::Flat), 3);
+    let e_flat = Pitch::new(NoteName::new(Letter::E, Accidental::Flat), 4);
+    assert_eq!(ChromaticTransposer.transpose(b_flat, 5), e_flat);
+
+    let f_sharp = Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4);
+    let c_sharp = Pitch::new(NoteName::new(Letter::C, Accidental::Sharp), 4);
+    assert_eq!(ChromaticTransposer.transpose(f_sharp, -5), c_sharp);
+}
+
+#[test]
+fn test_transpose_follows_direction_for_naturals() {
+    let d4 = Pitch::new(NoteName::new(Letter::D, Accidental::Natural), 4);
+    assert_eq!(
+        d4.transpose(1),
+        Pitch::new(NoteName::new(Letter::D, Accidental::Sharp), 4)
+    );
+    assert_eq!(
+        d4.transpose(-1),
+        Pitch::new(NoteName::new(Letter::D, Accidental::Flat), 4)
+    );
+}
+
+#[test]
+fn test_transpose_across_octaves() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    assert_eq!(c4.transpose(24).midi_number(), 84);
+    assert_eq!(c4.transpose(-13).midi_number(), 47);
+}
+
+#[test]
+fn test_tritone_transpositions_are_stable() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    assert_eq!(
+        c4.transpose(6),
+        Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4)
+    );
+    assert_eq!(
+        c4.transpose(-6),
+        Pitch::new(NoteName::new(Letter::G, Accidental::Flat), 3)
+    );
+
+    let b_flat = Pitch::new(NoteName::new(Letter::B, Accidental::Flat), 3);
+    assert_eq!(
+        b_flat.transpose(6),
+        Pitch::new(NoteName::new(Letter::E, Accidental::Natural), 4)
+    );
+
+    let f_sharp = Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4);
+    assert_eq!(
+        f_sharp.transpose(-6),
+        Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4)
+    );
+
+    let e_flat = Pitch::new(NoteName::new(Letter::E, Accidental::Flat), 4);
+    assert_eq!(
+        e_flat.transpose(6),
+        Pitch::new(NoteName::new(Letter::A, Accidental::Natural), 4)
+    );
+    assert_eq!(
+        e_flat.transpose(7),
+        Pitch::new(NoteName::new(Letter::B, Accidental::Flat), 4)
+    );
+}
+
+#[test]
+fn test_double_accidentals_are_simplified() {
+    let f_double_sharp = Pitch::new(NoteName::new(Letter::F, Accidental::DoubleSharp), 4);
+    assert_eq!(
+        ChromaticTransposer.transpose(f_double_sharp, 0),
+        Pitch::new(NoteName::new(Letter::G, Accidental::Natural), 4)
+    );
+
+    let b_double_flat = Pitch::new(NoteName::new(Letter::B, Accidental::DoubleFlat), 4);
+    assert_eq!(
+        ChromaticTransposer.transpose(b_double_flat, 1),
+        Pitch::new(NoteName::new(Letter::B, Accidental::Flat), 4)
+    );
+}
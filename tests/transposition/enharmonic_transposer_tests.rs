@@ -0,0 +1,65 @@
+use chordy::transposition::{ChromaticTransposer, EnharmonicTransposer, Transposer};
+use chordy::types::*;
+
+#[test]
+fn test_augmented_fourth_and_diminished_fifth_differ() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+
+    let f_sharp = EnharmonicTransposer.transpose_by_interval(c4, Interval::AUGMENTED_FOURTH);
+    let g_flat = EnharmonicTransposer.transpose_by_interval(c4, Interval::DIMINISHED_FIFTH);
+    assert_eq!(
+        f_sharp,
+        Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4)
+    );
+    assert_eq!(
+        g_flat,
+        Pitch::new(NoteName::new(Letter::G, Accidental::Flat), 4)
+    );
+    assert_ne!(f_sharp, g_flat);
+    assert_eq!(f_sharp.midi_number(), g_flat.midi_number());
+}
+
+#[test]
+fn test_double_accidentals_follow_the_interval() {
+    let e_sharp = Pitch::new(NoteName::new(Letter::E, Accidental::Sharp), 4);
+    assert_eq!(
+        EnharmonicTransposer.transpose_by_interval(e_sharp, Interval::MAJOR_THIRD),
+        Pitch::new(NoteName::new(Letter::G, Accidental::DoubleSharp), 4)
+    );
+
+    let c = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    assert_eq!(
+        EnharmonicTransposer.transpose_by_interval(c, Interval::DIMINISHED_SEVENTH),
+        Pitch::new(NoteName::new(Letter::B, Accidental::DoubleFlat), 4)
+    );
+
+    // The chromatic heuristic simplifies the same pitch
+    assert_eq!(
+        ChromaticTransposer.transpose(e_sharp, 4),
+        Pitch::new(NoteName::new(Letter::A, Accidental::Natural), 4)
+    );
+}
+
+#[test]
+fn test_semitone_transposition_moves_letters() {
+    let b3 = Pitch::new(NoteName::new(Letter::B, Accidental::Natural), 3);
+    assert_eq!(
+        EnharmonicTransposer.transpose(b3, 1),
+        Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4)
+    );
+
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    assert_eq!(
+        EnharmonicTransposer.transpose(c4, 1),
+        Pitch::new(NoteName::new(Letter::D, Accidental::Flat), 4)
+    );
+    assert_eq!(
+        EnharmonicTransposer.transpose(c4, 6),
+        Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4)
+    );
+    assert_eq!(
+        EnharmonicTransposer.transpose(c4, -3),
+        Pitch::new(NoteName::new(Letter::A, Accidental::Natural), 3)
+    );
+    assert_eq!(EnharmonicTransposer.transpose(c4, 19).midi_number(), 79);
+}
@@ -0,0 +1,52 @@
+use chordy::transposition::respell_melody;
+use chordy::types::*;
+use chordy::{note, pitch};
+
+fn names(pitches: &[Pitch]) -> Vec<String> {
+    pitches.iter().map(|p| p.to_string_ascii()).collect()
+}
+
+#[test]
+fn test_respell_ascending_chromatic_run() {
+    let run: Vec<Pitch> = (60..=72).map(Pitch::from_midi).collect();
+    let spelled = respell_melody(&run, &Key::major(NoteName::C));
+    assert_eq!(
+        names(&spelled),
+        [
+            "C4", "C#4", "D4", "D#4", "E4", "F4", "F#4", "G4", "G#4", "A4", "A#4", "B4", "C5"
+        ]
+    );
+}
+
+#[test]
+fn test_respell_descending_chromatic_run() {
+    let run: Vec<Pitch> = (60..=72).rev().map(Pitch::from_midi).collect();
+    let spelled = respell_melody(&run, &Key::major(NoteName::C));
+    assert_eq!(
+        names(&spelled),
+        [
+            "C5", "B4", "Bb4", "A4", "Ab4", "G4", "Gb4", "F4", "E4", "Eb4", "D4", "Db4", "C4"
+        ]
+    );
+}
+
+#[test]
+fn test_respell_uses_key_spelling() {
+    // Diatonic notes follow the key even when the input is spelled otherwise
+    let melody = [pitch!("A#3"), pitch!("D4"), pitch!("D#4"), pitch!("F4")];
+    let spelled = respell_melody(&melody, &Key::major(note!("Bb")));
+    assert_eq!(names(&spelled), ["Bb3", "D4", "Eb4", "F4"]);
+
+    // Octave numbers follow the letter: B♯3 respelled in C is C4
+    let spelled = respell_melody(&[pitch!("B#3")], &Key::major(NoteName::C));
+    assert_eq!(spelled, [pitch!("C4")]);
+}
+
+#[test]
+fn test_respell_repeated_note_follows_key_signature() {
+    let melody = [pitch!("C#4"), pitch!("C#4")];
+    let flats = respell_melody(&melody, &Key::major(NoteName::F));
+    assert_eq!(names(&flats), ["Db4", "Db4"]);
+    let sharps = respell_melody(&melody, &Key::major(NoteName::G));
+    assert_eq!(names(&sharps), ["C#4", "C#4"]);
+}
@@ -0,0 +1,5 @@
+mod batch_tests;
+mod chromatic_transposer_tests;
+mod enharmonic_transposer_tests;
+mod melody_tests;
+mod spelled_transposer_tests;
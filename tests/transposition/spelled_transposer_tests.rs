@@ -0,0 +1,86 @@
+use chordy::transposition::{SpelledTransposer, Transposer};
+use chordy::types::*;
+
+fn chromatic_octave(transposer: &SpelledTransposer) -> Vec<NoteName> {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    (0..12)
+        .map(|semitones| transposer.transpose(c4, semitones).name())
+        .collect()
+}
+
+#[test]
+fn test_prefer_flats() {
+    let transposer = SpelledTransposer {
+        prefer: Accidental::Flat,
+    };
+    let flat = |letter| NoteName::new(letter, Accidental::Flat);
+    let natural = |letter| NoteName::new(letter, Accidental::Natural);
+    assert_eq!(
+        chromatic_octave(&transposer),
+        vec![
+            natural(Letter::C),
+            flat(Letter::D),
+            natural(Letter::D),
+            flat(Letter::E),
+            natural(Letter::E),
+            natural(Letter::F),
+            flat(Letter::G),
+            natural(Letter::G),
+            flat(Letter::A),
+            natural(Letter::A),
+            flat(Letter::B),
+            natural(Letter::B),
+        ]
+    );
+}
+
+#[test]
+fn test_prefer_sharps() {
+    let transposer = SpelledTransposer {
+        prefer: Accidental::Sharp,
+    };
+    let names = chromatic_octave(&transposer);
+    assert_eq!(names[1], NoteName::new(Letter::C, Accidental::Sharp));
+    assert_eq!(names[3], NoteName::new(Letter::D, Accidental::Sharp));
+    assert_eq!(names[6], NoteName::new(Letter::F, Accidental::Sharp));
+    assert_eq!(names[8], NoteName::new(Letter::G, Accidental::Sharp));
+    assert_eq!(names[10], NoteName::new(Letter::A, Accidental::Sharp));
+    assert!(
+        names
+            .iter()
+            .all(|n| matches!(n.accidental(), Accidental::Natural | Accidental::Sharp))
+    );
+}
+
+#[test]
+fn test_preference_overrides_source_and_direction() {
+    let flats = SpelledTransposer {
+        prefer: Accidental::Flat,
+    };
+    let f_sharp = Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4);
+    assert_eq!(
+        flats.transpose(f_sharp, 2),
+        Pitch::new(NoteName::new(Letter::A, Accidental::Flat), 4)
+    );
+
+    let sharps = SpelledTransposer {
+        prefer: Accidental::Sharp,
+    };
+    let d4 = Pitch::new(NoteName::new(Letter::D, Accidental::Natural), 4);
+    assert_eq!(
+        sharps.transpose(d4, -1),
+        Pitch::new(NoteName::new(Letter::C, Accidental::Sharp), 4)
+    );
+}
+
+#[test]
+fn test_natural_preference_breaks_ties_towards_sharps() {
+    let naturals = SpelledTransposer {
+        prefer: Accidental::Natural,
+    };
+    let d_flat = Pitch::new(NoteName::new(Letter::D, Accidental::Flat), 4);
+    assert_eq!(
+        naturals.transpose(d_flat, 0),
+        Pitch::new(NoteName::new(Letter::C, Accidental::Sharp), 4)
+    );
+}
@@ -0,0 +1 @@
+mod tuning_tests;
@@ -0,0 +1,42 @@
+use chordy::tuning::Tuning;
+use chordy::{cents_between, note, pitch};
+
+#[test]
+fn test_equal_temperament_matches_pitch() {
+    let tuning = Tuning::EqualTemperament;
+    assert_eq!(tuning.frequency(pitch!("A4")), 440.0);
+    assert_eq!(
+        tuning.frequency(pitch!("Eb2")),
+        pitch!("Eb2").to_frequency()
+    );
+}
+
+#[test]
+fn test_just_major_third() {
+    let just = Tuning::JustIntonation { tonic: note!("C") };
+    let equal = Tuning::EqualTemperament;
+
+    // The tonic is shared by both tunings
+    assert_eq!(just.frequency(pitch!("C4")), equal.frequency(pitch!("C4")));
+
+    let c4 = just.frequency(pitch!("C4"));
+    let e4 = just.frequency(pitch!("E4"));
+    assert!((e4 / c4 - 1.25).abs() < 1e-12);
+
+    // The just third is about 14 cents flatter than the tempered one
+    let difference = cents_between(equal.frequency(pitch!("E4")), e4);
+    assert!((difference + 13.69).abs() < 0.01);
+}
+
+#[test]
+fn test_just_intonation_across_octaves() {
+    let just = Tuning::JustIntonation { tonic: note!("D") };
+    let d3 = just.frequency(pitch!("D3"));
+
+    assert!((just.frequency(pitch!("A3")) / d3 - 1.5).abs() < 1e-12);
+    assert!((just.frequency(pitch!("C4")) / d3 - 1.8).abs() < 1e-12);
+    assert!((just.frequency(pitch!("F#5")) / d3 - 5.0).abs() < 1e-12);
+
+    // Notes below the tonic use the tonic from the octave beneath
+    assert!((just.frequency(pitch!("C3")) / d3 - 0.9).abs() < 1e-12);
+}
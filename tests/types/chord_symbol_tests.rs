@@ -0,0 +1,97 @@
+use chordy::error::ParseError;
+use chordy::types::*;
+
+fn parse(symbol: &str) -> Chord {
+    symbol
+        .parse()
+        .unwrap_or_else(|e| panic!("{}: {:?}", symbol, e))
+}
+
+#[test]
+fn test_chord_symbol_root_is_longest_note_name() {
+    let e_flat = NoteName::new(Letter::E, Accidental::Flat);
+    assert_eq!(parse("Ebm"), Chord::minor(e_flat));
+    assert_eq!(parse("E♭m"), Chord::minor(e_flat));
+
+    let b_double_flat = NoteName::new(Letter::B, Accidental::DoubleFlat);
+    assert_eq!(parse("Bbb"), Chord::major(b_double_flat));
+
+    let c_sharp = NoteName::new(Letter::C, Accidental::Sharp);
+    assert_eq!(parse("C#m7"), Chord::seventh(c_sharp, SeventhType::Minor));
+}
+
+#[test]
+fn test_chord_symbol_quality_aliases() {
+    assert_eq!(parse("Cmin"), parse("Cm"));
+    assert_eq!(parse("Cdim"), parse("C°"));
+    assert_eq!(parse("Caug"), parse("C+"));
+    assert_eq!(parse("Cmin7"), parse("Cm7"));
+    assert_eq!(parse("Caug7"), parse("C+7"));
+    assert_eq!(parse("Cm7♭5"), parse("Cø7"));
+    assert_eq!(parse("Cø"), parse("Cø7"));
+}
+
+#[test]
+fn test_chord_symbol_added_tones() {
+    assert_eq!(parse("C69"), parse("C6/9"));
+    assert_eq!(parse("Cadd2"), parse("Cadd9"));
+    assert_eq!(parse("Cadd4"), parse("Cadd11"));
+
+    let add13 = parse("Cadd13");
+    assert_eq!(
+        add13,
+        Chord::major(NoteName::C).add_interval(Interval::MAJOR_THIRTEENTH)
+    );
+    assert!(add13.extended_type().is_none());
+}
+
+#[test]
+fn test_chord_symbol_extensions_stack_thirds() {
+    let c11 = parse("C11");
+    assert!(c11.contains_note(&NoteName::D));
+    assert!(c11.contains_note(&NoteName::F));
+    assert!(c11.contains_note(&NoteName::new(Letter::B, Accidental::Flat)));
+
+    let cmaj13 = parse("Cmaj13");
+    assert!(cmaj13.contains_note(&NoteName::B));
+    assert!(cmaj13.contains_note(&NoteName::A));
+}
+
+#[test]
+fn test_chord_symbol_suspensions() {
+    assert_eq!(parse("Csus"), parse("Csus4"));
+
+    let c7sus4 = parse("C7sus4");
+    assert!(c7sus4.contains_note(&NoteName::F));
+    assert!(!c7sus4.contains_note(&NoteName::E));
+    assert_eq!(c7sus4.quality(), ChordQuality::Sus4);
+}
+
+#[test]
+fn test_chord_symbol_groups() {
+    assert_eq!(parse("C7(b9)(#11)"), parse("C7(♭9♯11)"));
+
+    let flat_five = parse("C7(b5)");
+    assert!(flat_five.contains_note(&NoteName::new(Letter::G, Accidental::Flat)));
+    assert!(!flat_five.contains_note(&NoteName::G));
+
+    let no_third = parse("Cmaj7(no3)");
+    assert!(!no_third.contains_note(&NoteName::E));
+    assert!(no_third.contains_note(&NoteName::B));
+}
+
+#[test]
+fn test_malformed_chord_symbols() {
+    let malformed = [
+        "", "m7", "c", "C7(b9", "C7()", "C7(b9)x", "C7)", "C7(#10)", "C7(b)", "C7(bb9)", "C8",
+        "Cmaj", "Csus3",
+    ];
+    for symbol in malformed {
+        assert_eq!(
+            symbol.parse::<Chord>(),
+            Err(ParseError::InvalidChordSymbol(symbol.to_string())),
+            "{}",
+            symbol
+        );
+    }
+}
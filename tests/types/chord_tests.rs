@@ -1,4 +1,1399 @@
+use chordy::scales;
+use chordy::types::*;
+
 #[test]
 fn test_chord_creation() {
-    // Will test Chord::new()
+    let chord = Chord::new(
+        NoteName::new(Letter::D, Accidental::Natural),
+        ChordQuality::Major,
+    );
+    assert_eq!(
+        chord.notes(),
+        vec![
+            NoteName::new(Letter::D, Accidental::Natural),
+            NoteName::new(Letter::F, Accidental::Sharp),
+            NoteName::new(Letter::A, Accidental::Natural),
+        ]
+    );
+
+    let chord = Chord::diminished(NoteName::new(Letter::B, Accidental::Natural));
+    assert_eq!(
+        chord.notes()[2],
+        NoteName::new(Letter::F, Accidental::Natural)
+    );
+}
+
+#[test]
+fn test_chord_to_abc() {
+    let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    assert_eq!(c_major.to_abc(), "[CEG]");
+
+    let a_minor = Chord::minor(NoteName::new(Letter::A, Accidental::Natural));
+    assert_eq!(a_minor.to_abc(), "[Ace]");
+
+    let e_flat_major = Chord::major(NoteName::new(Letter::E, Accidental::Flat));
+    assert_eq!(e_flat_major.to_abc(), "[_EG_B]");
+}
+
+#[test]
+fn test_chord_to_lilypond() {
+    let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    assert_eq!(c_major.to_lilypond(), "<c' e' g'>");
+
+    let b_major = Chord::major(NoteName::new(Letter::B, Accidental::Natural));
+    assert_eq!(b_major.to_lilypond(), "<b' dis'' fis''>");
+}
+
+#[test]
+fn test_chord_to_midi_triad() {
+    let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    assert_eq!(c_major.to_midi(4), vec![60, 64, 67]);
+
+    let a_minor = Chord::minor(NoteName::new(Letter::A, Accidental::Natural));
+    assert_eq!(a_minor.to_midi(3), vec![57, 60, 64]);
+}
+
+#[test]
+fn test_chord_to_midi_seventh() {
+    let g7 = Chord::seventh(
+        NoteName::new(Letter::G, Accidental::Natural),
+        SeventhType::Dominant,
+    );
+    assert_eq!(g7.to_midi(3), vec![55, 59, 62, 65]);
+}
+
+#[test]
+fn test_chord_to_midi_top_of_range() {
+    // B9 and D10 are above 127, so the whole chord drops an octave
+    let g_major = Chord::major(NoteName::new(Letter::G, Accidental::Natural));
+    assert_eq!(g_major.to_midi(9), vec![115, 119, 122]);
+
+    let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    assert_eq!(c_major.to_midi(-2), vec![0, 4, 7]);
+}
+
+#[test]
+fn test_chord_to_midi_round_trip() {
+    let g7 = Chord::seventh(NoteName::G, SeventhType::Dominant);
+    for chord in [Chord::major(NoteName::C), g7.inverted(2)] {
+        for octave in [2, 4, 6] {
+            // Every tone is natural, so from_midi spells them as voiced
+            let pitches: Vec<Pitch> = chord
+                .to_midi(octave)
+                .into_iter()
+                .map(Pitch::from_midi)
+                .collect();
+            assert_eq!(pitches, chord.voice(octave));
+        }
+    }
+}
+
+#[test]
+fn test_chord_from_notes_fallback() {
+    // C, D, F# doesn't match a known shape, so C is taken as the root
+    let notes = [
+        NoteName::new(Letter::C, Accidental::Natural),
+        NoteName::new(Letter::D, Accidental::Natural),
+        NoteName::new(Letter::F, Accidental::Sharp),
+    ];
+    let chord = Chord::from_notes(&notes);
+    assert_eq!(chord.root(), notes[0]);
+    assert_eq!(chord.notes(), notes.to_vec());
+}
+
+#[test]
+fn test_chord_from_notes_empty() {
+    let chord = Chord::from_notes(&[]);
+    assert_eq!(chord.root(), NoteName::C);
+    assert!(chord.notes().is_empty());
+    assert!(Chord::from_midi(&[]).notes().is_empty());
+}
+
+#[test]
+fn test_chord_from_midi_major() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    assert_eq!(Chord::from_midi(&[60, 64, 67]), Chord::major(c));
+
+    // Octave doublings and inversions still find the root
+    assert_eq!(Chord::from_midi(&[52, 60, 64, 67, 72]), Chord::major(c));
+}
+
+#[test]
+fn test_chord_from_midi_minor() {
+    let a = NoteName::new(Letter::A, Accidental::Natural);
+    assert_eq!(Chord::from_midi(&[57, 60, 64]), Chord::minor(a));
+}
+
+#[test]
+fn test_chord_from_midi_dominant_seventh() {
+    let g = NoteName::new(Letter::G, Accidental::Natural);
+    assert_eq!(
+        Chord::from_midi(&[55, 59, 62, 65]),
+        Chord::seventh(g, SeventhType::Dominant)
+    );
+}
+
+#[test]
+fn test_chord_from_iterator() {
+    let d = NoteName::new(Letter::D, Accidental::Natural);
+    let chord: Chord = Chord::major(d).notes().into_iter().rev().collect();
+    assert_eq!(chord, Chord::major(d));
+
+    let chord: Chord = [
+        NoteName::new(Letter::B, Accidental::Natural),
+        NoteName::new(Letter::D, Accidental::Natural),
+        NoteName::new(Letter::F, Accidental::Natural),
+        NoteName::new(Letter::G, Accidental::Natural),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        chord,
+        Chord::seventh(
+            NoteName::new(Letter::G, Accidental::Natural),
+            SeventhType::Dominant
+        )
+    );
+}
+
+#[test]
+fn test_chord_into_iterator() {
+    let chord = Chord::seventh(
+        NoteName::new(Letter::E, Accidental::Flat),
+        SeventhType::Major,
+    );
+    let expected = [
+        NoteName::new(Letter::E, Accidental::Flat),
+        NoteName::new(Letter::G, Accidental::Natural),
+        NoteName::new(Letter::B, Accidental::Flat),
+        NoteName::new(Letter::D, Accidental::Natural),
+    ];
+
+    let mut seen = Vec::new();
+    for note in &chord {
+        seen.push(note);
+    }
+    assert_eq!(seen, expected);
+
+    let owned: Vec<NoteName> = chord.into_iter().collect();
+    assert_eq!(owned, expected);
+}
+
+#[test]
+fn test_contains_note() {
+    let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    assert!(c_major.contains_note(&NoteName::new(Letter::C, Accidental::Natural)));
+    assert!(c_major.contains_note(&NoteName::new(Letter::E, Accidental::Natural)));
+    assert!(c_major.contains_note(&NoteName::new(Letter::G, Accidental::Natural)));
+}
+
+#[test]
+fn test_contains_note_enharmonic() {
+    let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    assert!(c_major.contains_note(&NoteName::new(Letter::F, Accidental::Flat)));
+    assert!(c_major.contains_note(&NoteName::new(Letter::B, Accidental::Sharp)));
+    assert!(c_major.contains_note(&NoteName::new(Letter::F, Accidental::DoubleSharp)));
+
+    // C♭ sits below C in base MIDI numbers but still matches B
+    let g_major = Chord::major(NoteName::new(Letter::G, Accidental::Natural));
+    assert!(g_major.contains_note(&NoteName::new(Letter::C, Accidental::Flat)));
+}
+
+#[test]
+fn test_contains_note_absent() {
+    let c_major = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    assert!(!c_major.contains_note(&NoteName::new(Letter::E, Accidental::Flat)));
+    assert!(!c_major.contains_note(&NoteName::new(Letter::F, Accidental::Natural)));
+    assert!(!c_major.contains_note(&NoteName::new(Letter::B, Accidental::Natural)));
+}
+
+#[test]
+fn test_respell_for_flat_key() {
+    let e_flat_major = Key::major(NoteName::new(Letter::E, Accidental::Flat));
+
+    // G♯-B♯-D♯ reads better as A♭-C-E♭ in E♭ major
+    let g_sharp = Chord::major(NoteName::new(Letter::G, Accidental::Sharp));
+    let respelled = g_sharp.respell_for_key(&e_flat_major);
+    assert_eq!(
+        respelled.notes(),
+        vec![
+            NoteName::new(Letter::A, Accidental::Flat),
+            NoteName::new(Letter::C, Accidental::Natural),
+            NoteName::new(Letter::E, Accidental::Flat),
+        ]
+    );
+
+    // D♯-F𝄪-A♯ becomes E♭-G-B♭
+    let d_sharp = Chord::major(NoteName::new(Letter::D, Accidental::Sharp));
+    assert_eq!(
+        d_sharp.respell_for_key(&e_flat_major),
+        Chord::major(NoteName::new(Letter::E, Accidental::Flat))
+    );
+}
+
+#[test]
+fn test_respell_for_sharp_key() {
+    let e_major = Key::major(NoteName::new(Letter::E, Accidental::Natural));
+
+    let a_flat = Chord::major(NoteName::new(Letter::A, Accidental::Flat));
+    assert_eq!(
+        a_flat.respell_for_key(&e_major),
+        Chord::major(NoteName::new(Letter::G, Accidental::Sharp))
+    );
+
+    let d_flat_minor = Chord::minor(NoteName::new(Letter::D, Accidental::Flat));
+    assert_eq!(
+        d_flat_minor.respell_for_key(&e_major),
+        Chord::minor(NoteName::new(Letter::C, Accidental::Sharp))
+    );
+}
+
+#[test]
+fn test_respell_keeps_fitting_chords() {
+    let c_major = Key::major(NoteName::new(Letter::C, Accidental::Natural));
+    let g7 = Chord::seventh(
+        NoteName::new(Letter::G, Accidental::Natural),
+        SeventhType::Dominant,
+    );
+    assert_eq!(g7.respell_for_key(&c_major), g7);
+}
+
+#[cfg(feature = "utf8_symbols")]
+fn names(chord: &Chord) -> Vec<String> {
+    chord.notes().iter().map(|n| n.to_string()).collect()
+}
+
+#[cfg(feature = "utf8_symbols")]
+#[test]
+fn test_augmented_sixths_in_c() {
+    let key = Key::minor(NoteName::new(Letter::C, Accidental::Natural));
+    assert_eq!(names(&Chord::italian_sixth(&key)), ["A♭", "C", "F♯"]);
+    assert_eq!(names(&Chord::french_sixth(&key)), ["A♭", "C", "D", "F♯"]);
+    assert_eq!(names(&Chord::german_sixth(&key)), ["A♭", "C", "E♭", "F♯"]);
+
+    // The major key uses the same chords
+    let major = Key::major(NoteName::new(Letter::C, Accidental::Natural));
+    assert_eq!(Chord::german_sixth(&major), Chord::german_sixth(&key));
+}
+
+#[cfg(feature = "utf8_symbols")]
+#[test]
+fn test_augmented_sixths_in_other_keys() {
+    let a_minor = Key::minor(NoteName::new(Letter::A, Accidental::Natural));
+    assert_eq!(names(&Chord::italian_sixth(&a_minor)), ["F", "A", "D♯"]);
+    assert_eq!(names(&Chord::french_sixth(&a_minor)), ["F", "A", "B", "D♯"]);
+    assert_eq!(names(&Chord::german_sixth(&a_minor)), ["F", "A", "C", "D♯"]);
+
+    // The sixth is augmented, not a minor seventh: C𝄪 rather than D
+    let g_sharp_minor = Key::minor(NoteName::new(Letter::G, Accidental::Sharp));
+    assert_eq!(
+        names(&Chord::italian_sixth(&g_sharp_minor)),
+        ["E", "G♯", "C𝄪"]
+    );
+}
+
+#[test]
+fn test_quartal_on_c() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+
+    let three = Chord::quartal(c, 3);
+    assert_eq!(
+        three.notes(),
+        vec![
+            c,
+            NoteName::new(Letter::F, Accidental::Natural),
+            NoteName::new(Letter::B, Accidental::Flat),
+        ]
+    );
+    assert!(three.is_quartal());
+
+    let four = Chord::quartal(c, 4);
+    assert_eq!(four.notes()[3], NoteName::new(Letter::E, Accidental::Flat));
+    assert_eq!(four.to_midi(4), vec![60, 65, 70, 75]);
+    assert!(four.is_quartal());
+}
+
+#[test]
+fn test_quartal_on_f_sharp() {
+    let f_sharp = NoteName::new(Letter::F, Accidental::Sharp);
+    let chord = Chord::quartal(f_sharp, 4);
+    assert_eq!(
+        chord.notes(),
+        vec![
+            f_sharp,
+            NoteName::new(Letter::B, Accidental::Natural),
+            NoteName::new(Letter::E, Accidental::Natural),
+            NoteName::new(Letter::A, Accidental::Natural),
+        ]
+    );
+    assert!(chord.is_quartal());
+    assert!(Chord::quartal(f_sharp, 3).is_quartal());
+}
+
+#[test]
+fn test_tertian_chords_are_not_quartal() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    assert!(!Chord::major(c).is_quartal());
+    assert!(!Chord::new(c, ChordQuality::Sus4).is_quartal());
+    assert!(!Chord::quartal(c, 2).is_quartal());
+}
+
+#[test]
+fn test_with_extension() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let c7 = Chord::seventh(c, SeventhType::Dominant);
+
+    let c7_flat5 = c7.with_extension(ChordExtension::AlteredFifth(AlteredFifthType::Flat));
+    assert_eq!(
+        c7_flat5.notes()[2],
+        NoteName::new(Letter::G, Accidental::Flat)
+    );
+    assert_eq!(c7_flat5.notes().len(), 4);
+
+    let c7_sus4 = c7.with_extension(ChordExtension::Sus(SuspendedType::Sus4));
+    assert_eq!(
+        c7_sus4.notes()[1],
+        NoteName::new(Letter::F, Accidental::Natural)
+    );
+
+    let c_maj7 = c7.with_extension(ChordExtension::Seventh(SeventhType::Major));
+    assert_eq!(c_maj7, Chord::seventh(c, SeventhType::Major));
+
+    let c7_no5 = c7.with_extension(ChordExtension::Omit(OmittedNote::No5));
+    assert_eq!(c7_no5.notes().len(), 3);
+}
+
+fn thirteenth_chord(eleventh: EleventhType) -> Chord {
+    Chord::seventh(
+        NoteName::new(Letter::C, Accidental::Natural),
+        SeventhType::Dominant,
+    )
+    .with_extension(ChordExtension::Ninth(NinthType::Natural))
+    .with_extension(ChordExtension::Eleventh(eleventh))
+    .with_extension(ChordExtension::Thirteenth(ThirteenthType::Natural))
+}
+
+#[test]
+fn test_upper_structure_of_thirteen_sharp_eleven() {
+    let chord = thirteenth_chord(EleventhType::Sharp);
+    assert_eq!(
+        chord.upper_structures(),
+        vec![Chord::major(NoteName::new(Letter::D, Accidental::Natural))]
+    );
+}
+
+#[test]
+fn test_upper_structures_of_thirteen() {
+    let chord = thirteenth_chord(EleventhType::Natural);
+    assert_eq!(
+        chord.upper_structures(),
+        vec![
+            Chord::major(NoteName::new(Letter::B, Accidental::Flat)),
+            Chord::minor(NoteName::new(Letter::D, Accidental::Natural)),
+            Chord::major(NoteName::new(Letter::F, Accidental::Natural)),
+        ]
+    );
+}
+
+#[test]
+fn test_triads_have_no_upper_structures() {
+    let chord = Chord::seventh(
+        NoteName::new(Letter::C, Accidental::Natural),
+        SeventhType::Major,
+    );
+    assert!(chord.upper_structures().is_empty());
+}
+
+#[test]
+fn test_add_and_remove_interval() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let triad = Chord::major(c);
+
+    let seventh = triad.add_interval(Interval::MAJOR_SEVENTH);
+    assert_eq!(seventh, Chord::seventh(c, SeventhType::Major));
+    assert_eq!(seventh.add_interval(Interval::MAJOR_SEVENTH), seventh);
+    assert_eq!(seventh.remove_interval(Interval::MAJOR_SEVENTH), triad);
+
+    // Removing an interval the chord doesn't have changes nothing
+    assert_eq!(seventh.remove_interval(Interval::MINOR_SEVENTH), seventh);
+}
+
+#[test]
+fn test_add_interval_keeps_order() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let chord = Chord::seventh(c, SeventhType::Major).add_interval(Interval::MAJOR_SIXTH);
+    assert_eq!(
+        chord.notes(),
+        vec![
+            c,
+            NoteName::new(Letter::E, Accidental::Natural),
+            NoteName::new(Letter::G, Accidental::Natural),
+            NoteName::new(Letter::A, Accidental::Natural),
+            NoteName::new(Letter::B, Accidental::Natural),
+        ]
+    );
+}
+
+#[test]
+fn test_common_tones() {
+    let c = Chord::major(NoteName::new(Letter::C, Accidental::Natural));
+    let e_minor = Chord::minor(NoteName::new(Letter::E, Accidental::Natural));
+    assert_eq!(
+        c.common_tones(&e_minor),
+        vec![
+            NoteName::new(Letter::E, Accidental::Natural),
+            NoteName::new(Letter::G, Accidental::Natural),
+        ]
+    );
+}
+
+#[test]
+fn test_changed_tones() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let c_major = Chord::major(c);
+
+    let (removed, added) = c_major.changed_tones(&Chord::seventh(c, SeventhType::Major));
+    assert!(removed.is_empty());
+    assert_eq!(added, vec![NoteName::new(Letter::B, Accidental::Natural)]);
+
+    let (removed, added) =
+        c_major.changed_tones(&Chord::minor(NoteName::new(Letter::A, Accidental::Natural)));
+    assert_eq!(removed, vec![NoteName::new(Letter::G, Accidental::Natural)]);
+    assert_eq!(added, vec![NoteName::new(Letter::A, Accidental::Natural)]);
+
+    let g7 = Chord::seventh(
+        NoteName::new(Letter::G, Accidental::Natural),
+        SeventhType::Dominant,
+    );
+    let (removed, added) = g7.changed_tones(&c_major);
+    assert_eq!(
+        removed,
+        vec![
+            NoteName::new(Letter::B, Accidental::Natural),
+            NoteName::new(Letter::D, Accidental::Natural),
+            NoteName::new(Letter::F, Accidental::Natural),
+        ]
+    );
+    assert_eq!(
+        added,
+        vec![c, NoteName::new(Letter::E, Accidental::Natural)]
+    );
+}
+
+#[test]
+fn test_changed_tones_match_enharmonically() {
+    let c_sharp_major = Chord::major(NoteName::new(Letter::C, Accidental::Sharp));
+    let d_flat_major = Chord::major(NoteName::new(Letter::D, Accidental::Flat));
+    let (removed, added) = c_sharp_major.changed_tones(&d_flat_major);
+    assert!(removed.is_empty());
+    assert!(added.is_empty());
+}
+
+fn scale_names(chord: &Chord) -> Vec<String> {
+    chord
+        .matching_scales()
+        .iter()
+        .map(|scale| scale.definition().name().to_string())
+        .collect()
+}
+
+#[test]
+fn test_matching_scales_for_major_seventh() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let chord = Chord::seventh(c, SeventhType::Major);
+    assert_eq!(
+        chord.matching_scales()[..2],
+        [Scale::new(c, scales::IONIAN), Scale::new(c, scales::LYDIAN)]
+    );
+    // Scales without the perfect fifth rank after the ones with all four tones
+    assert_eq!(
+        scale_names(&chord),
+        [
+            "Ionian",
+            "Lydian",
+            "Lydian Sharp 2",
+            "Augmented",
+            "Ionian Augmented",
+            "Lydian Augmented"
+        ]
+    );
+}
+
+#[test]
+fn test_matching_scales_for_dominant_seventh() {
+    let g = NoteName::new(Letter::G, Accidental::Natural);
+    let chord = Chord::seventh(g, SeventhType::Dominant);
+    assert_eq!(
+        chord.matching_scales()[0],
+        Scale::new(g, scales::MIXOLYDIAN)
+    );
+    assert_eq!(
+        scale_names(&chord),
+        [
+            "Mixolydian",
+            "Phrygian Dominant",
+            "Lydian Dominant",
+            "Mixolydian Flat 6",
+            "Altered",
+            "Whole Tone"
+        ]
+    );
+}
+
+#[test]
+fn test_matching_scales_for_minor_triad() {
+    let d = NoteName::new(Letter::D, Accidental::Natural);
+    assert_eq!(
+        scale_names(&Chord::minor(d)),
+        [
+            "Dorian",
+            "Phrygian",
+            "Aeolian",
+            "Harmonic Minor",
+            "Melodic Minor",
+            "Dorian Sharp 4",
+            "Lydian Sharp 2",
+            "Dorian Flat 2",
+            "Augmented",
+            "Minor Pentatonic",
+            "Locrian",
+            "Altered",
+            "Locrian Natural 6",
+            "Ultralocrian",
+            "Locrian Natural 2",
+            "Blues Minor Pentatonic"
+        ]
+    );
+}
+
+/// Reference triad search: every ordered combination of three tones
+fn naive_triads(chord_like: &impl ChordLike) -> Vec<Chord> {
+    let root = chord_like.root();
+    let notes: Vec<NoteName> = chord_like.intervals().iter().map(|&i| root + i).collect();
+
+    let mut triads = Vec::new();
+    for r in 0..notes.len() {
+        for t in 0..notes.len() {
+            for f in 0..notes.len() {
+                if r == t || r == f || t == f {
+                    continue;
+                }
+                let third = notes[t] - notes[r];
+                let fifth = notes[f] - notes[r];
+                if third.steps() != 2 || fifth.steps() != 4 {
+                    continue;
+                }
+                let quality = match (third.semitones(), fifth.semitones()) {
+                    (4, 7) => ChordQuality::Major,
+                    (3, 7) => ChordQuality::Minor,
+                    (3, 6) => ChordQuality::Diminished,
+                    (4, 8) => ChordQuality::Augmented,
+                    _ => continue,
+                };
+                triads.push(Chord::new(notes[r], quality));
+            }
+        }
+    }
+    triads
+}
+
+#[test]
+fn test_triads_from_scale_c_major() {
+    let n = |letter| NoteName::new(letter, Accidental::Natural);
+    let c_major = Scale::new(n(Letter::C), ScaleType::Major);
+    assert_eq!(
+        c_major.triads(),
+        vec![
+            Chord::major(n(Letter::C)),
+            Chord::minor(n(Letter::D)),
+            Chord::minor(n(Letter::E)),
+            Chord::major(n(Letter::F)),
+            Chord::major(n(Letter::G)),
+            Chord::minor(n(Letter::A)),
+            Chord::diminished(n(Letter::B)),
+        ]
+    );
+}
+
+#[test]
+fn test_triads_match_naive_search() {
+    let tonic = NoteName::new(Letter::E, Accidental::Flat);
+    for definition in scales::REGISTRY {
+        let scale = Scale::new(tonic, definition.clone());
+        assert_eq!(
+            scale.triads(),
+            naive_triads(&scale),
+            "{}",
+            definition.name()
+        );
+    }
+
+    let c13 = Chord::seventh(
+        NoteName::new(Letter::C, Accidental::Natural),
+        SeventhType::Dominant,
+    )
+    .with_extension(ChordExtension::Ninth(NinthType::Natural))
+    .with_extension(ChordExtension::Thirteenth(ThirteenthType::Natural));
+    assert_eq!(c13.triads(), naive_triads(&c13));
+}
+
+#[test]
+fn test_sevenths_from_scale_c_major() {
+    let n = |letter| NoteName::new(letter, Accidental::Natural);
+    let c_major = Scale::new(n(Letter::C), ScaleType::Major);
+    assert_eq!(
+        c_major.sevenths(),
+        vec![
+            Chord::seventh(n(Letter::C), SeventhType::Major),
+            Chord::seventh(n(Letter::D), SeventhType::Minor),
+            Chord::seventh(n(Letter::E), SeventhType::Minor),
+            Chord::seventh(n(Letter::F), SeventhType::Major),
+            Chord::seventh(n(Letter::G), SeventhType::Dominant),
+            Chord::seventh(n(Letter::A), SeventhType::Minor),
+            Chord::seventh(n(Letter::B), SeventhType::HalfDiminished),
+        ]
+    );
+    assert_eq!(c_major.possible_chords().len(), 14);
+}
+
+/// Reference seventh chord search: every ordered combination of four tones
+fn naive_sevenths(chord_like: &impl ChordLike) -> Vec<Chord> {
+    let root = chord_like.root();
+    let notes: Vec<NoteName> = chord_like.intervals().iter().map(|&i| root + i).collect();
+    let n = notes.len();
+
+    let mut sevenths = Vec::new();
+    for r in 0..n {
+        for t in 0..n {
+            for f in 0..n {
+                for s in 0..n {
+                    if r == t || r == f || r == s || t == f || t == s || f == s {
+                        continue;
+                    }
+                    let third = notes[t] - notes[r];
+                    let fifth = notes[f] - notes[r];
+                    let seventh = notes[s] - notes[r];
+                    if third.steps() != 2 || fifth.steps() != 4 || seventh.steps() != 6 {
+                        continue;
+                    }
+                    let seventh_type =
+                        match (third.semitones(), fifth.semitones(), seventh.semitones()) {
+                            (4, 7, 10) => SeventhType::Dominant,
+                            (4, 7, 11) => SeventhType::Major,
+                            (3, 7, 10) => SeventhType::Minor,
+                            (3, 6, 10) => SeventhType::HalfDiminished,
+                            (3, 6, 9) => SeventhType::Diminished,
+                            (3, 7, 11) => SeventhType::MinorMajor,
+                            (4, 8, 11) => SeventhType::AugmentedMajor,
+                            _ => continue,
+                        };
+                    sevenths.push(Chord::seventh(notes[r], seventh_type));
+                }
+            }
+        }
+    }
+    sevenths
+}
+
+#[test]
+fn test_sevenths_match_naive_search() {
+    let tonics = [
+        NoteName::new(Letter::C, Accidental::Natural),
+        NoteName::new(Letter::F, Accidental::Sharp),
+        NoteName::new(Letter::B, Accidental::Flat),
+    ];
+    for tonic in tonics {
+        for definition in scales::REGISTRY {
+            let scale = Scale::new(tonic, definition.clone());
+            assert_eq!(
+                scale.sevenths(),
+                naive_sevenths(&scale),
+                "{} {}",
+                tonic,
+                definition.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_chord_inversions() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let e = NoteName::new(Letter::E, Accidental::Natural);
+    let g = NoteName::new(Letter::G, Accidental::Natural);
+    let c_major = Chord::major(c);
+
+    let first = c_major.inverted(1);
+    assert_eq!(first.notes(), vec![e, g, c]);
+    assert_eq!(first.root(), c);
+    assert_eq!(first.quality(), ChordQuality::Major);
+    assert_eq!(first.to_midi(4), vec![64, 67, 72]);
+
+    let second = c_major.inverted(2);
+    assert_eq!(second.notes(), vec![g, c, e]);
+    assert_eq!(second.quality(), ChordQuality::Major);
+    assert_eq!(second, first.invert());
+
+    assert_eq!(c_major.inverted(3), c_major);
+    assert_eq!(second.invert(), c_major);
+}
+
+#[test]
+fn test_seventh_chord_inversions() {
+    let g7 = Chord::seventh(
+        NoteName::new(Letter::G, Accidental::Natural),
+        SeventhType::Dominant,
+    );
+    let third = g7.inverted(3);
+    assert_eq!(
+        third.notes()[0],
+        NoteName::new(Letter::F, Accidental::Natural)
+    );
+    assert_eq!(third.to_midi(4), vec![77, 79, 83, 86]);
+    assert_eq!(third.quality(), ChordQuality::Major);
+    assert_eq!(third.invert(), g7);
+}
+
+#[test]
+fn test_degree_intervals() {
+    let g7 = Chord::seventh(
+        NoteName::new(Letter::G, Accidental::Natural),
+        SeventhType::Dominant,
+    );
+    let pairs: Vec<(usize, Interval)> = g7.degree_intervals().collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (1, Interval::PERFECT_UNISON),
+            (2, Interval::MAJOR_THIRD),
+            (3, Interval::PERFECT_FIFTH),
+            (4, Interval::MINOR_SEVENTH),
+        ]
+    );
+
+    let scale = Scale::new(
+        NoteName::new(Letter::D, Accidental::Natural),
+        scales::DORIAN,
+    );
+    let sixth = scale
+        .degree_intervals()
+        .find(|(degree, _)| *degree == 6)
+        .map(|(_, interval)| interval);
+    assert_eq!(sixth, Some(Interval::MAJOR_SIXTH));
+    assert_eq!(scale.degree_intervals().count(), 7);
+}
+
+#[test]
+fn test_ninths_from_scale_c_major() {
+    let n = |letter| NoteName::new(letter, Accidental::Natural);
+    let c_major = Scale::new(n(Letter::C), ScaleType::Major);
+    let natural = ChordExtension::Ninth(NinthType::Natural);
+    let flat = ChordExtension::Ninth(NinthType::Flat);
+
+    assert_eq!(
+        c_major.ninths(),
+        vec![
+            Chord::seventh(n(Letter::C), SeventhType::Major).with_extension(natural),
+            Chord::seventh(n(Letter::D), SeventhType::Minor).with_extension(natural),
+            Chord::seventh(n(Letter::E), SeventhType::Minor).with_extension(flat),
+            Chord::seventh(n(Letter::F), SeventhType::Major).with_extension(natural),
+            Chord::seventh(n(Letter::G), SeventhType::Dominant).with_extension(natural),
+            Chord::seventh(n(Letter::A), SeventhType::Minor).with_extension(natural),
+            Chord::seventh(n(Letter::B), SeventhType::HalfDiminished).with_extension(flat),
+        ]
+    );
+}
+
+#[test]
+fn test_elevenths_and_thirteenths_from_scale_c_major() {
+    let n = |letter| NoteName::new(letter, Accidental::Natural);
+    let c_major = Scale::new(n(Letter::C), ScaleType::Major);
+
+    let elevenths = c_major.elevenths();
+    assert_eq!(elevenths.len(), 7);
+    assert_eq!(
+        elevenths[3],
+        Chord::seventh(n(Letter::F), SeventhType::Major)
+            .with_extension(ChordExtension::Ninth(NinthType::Natural))
+            .with_extension(ChordExtension::Eleventh(EleventhType::Sharp))
+    );
+
+    let thirteenths = c_major.thirteenths();
+    assert_eq!(thirteenths.len(), 7);
+    assert_eq!(
+        thirteenths[4].notes(),
+        vec![
+            n(Letter::G),
+            n(Letter::B),
+            n(Letter::D),
+            n(Letter::F),
+            n(Letter::A),
+            n(Letter::C),
+            n(Letter::E),
+        ]
+    );
+}
+
+#[test]
+fn test_default_chord_is_c_major() {
+    let chord = Chord::default();
+    assert_eq!(
+        chord,
+        Chord::major(NoteName::new(Letter::C, Accidental::Natural))
+    );
+    assert_eq!(chord.quality(), ChordQuality::Major);
+}
+
+#[test]
+fn test_display_abbreviated_name() {
+    assert_eq!(
+        Chord::seventh(NoteName::C, SeventhType::Major).to_string(),
+        "Cmaj7"
+    );
+    assert_eq!(Chord::minor(NoteName::A).to_string(), "Am");
+    assert_eq!(
+        Chord::seventh(NoteName::G, SeventhType::Dominant).to_string(),
+        "G7"
+    );
+    assert_eq!(
+        Chord::seventh(NoteName::B, SeventhType::HalfDiminished).to_string(),
+        "Bø7"
+    );
+}
+
+#[test]
+fn test_display_alternate_full_name() {
+    assert_eq!(
+        format!("{:#}", Chord::seventh(NoteName::C, SeventhType::Major)),
+        "C major seventh"
+    );
+    assert_eq!(format!("{:#}", Chord::minor(NoteName::A)), "A minor");
+    assert_eq!(
+        format!("{:#}", Chord::seventh(NoteName::G, SeventhType::Dominant)),
+        "G dominant seventh"
+    );
+    assert_eq!(
+        format!(
+            "{:#}",
+            Chord::seventh(NoteName::B, SeventhType::HalfDiminished)
+        ),
+        "B half-diminished seventh"
+    );
+    assert_eq!(
+        format!("{:#}", Chord::seventh(NoteName::B, SeventhType::Diminished)),
+        "B diminished seventh"
+    );
+    assert_eq!(
+        format!("{:#}", Chord::new(NoteName::D, ChordQuality::Sus4)),
+        "D suspended fourth"
+    );
+    assert_eq!(
+        format!(
+            "{:#}",
+            Chord::new(NoteName::D, ChordQuality::Sus4)
+                .with_extension(ChordExtension::Seventh(SeventhType::Dominant))
+        ),
+        "D seventh suspended fourth"
+    );
+    assert_eq!(
+        format!("{:#}", Chord::augmented(NoteName::E)),
+        "E augmented"
+    );
+}
+
+#[test]
+fn test_extended_type() {
+    assert_eq!(
+        Chord::seventh(NoteName::D, SeventhType::Minor).extended_type(),
+        Some(SeventhType::Minor)
+    );
+    assert_eq!(
+        Chord::seventh(NoteName::A, SeventhType::MinorMajor).extended_type(),
+        Some(SeventhType::MinorMajor)
+    );
+    assert_eq!(Chord::major(NoteName::C).extended_type(), None);
+}
+
+#[test]
+fn test_transposition_symmetry() {
+    let augmented = Chord::augmented(NoteName::C);
+    assert_eq!(augmented.transposition_symmetry(), 4);
+    assert!(augmented.is_symmetric());
+
+    let diminished_7th = Chord::seventh(NoteName::B, SeventhType::Diminished);
+    assert_eq!(diminished_7th.transposition_symmetry(), 3);
+    assert!(diminished_7th.is_symmetric());
+
+    let major = Chord::major(NoteName::C);
+    assert_eq!(major.transposition_symmetry(), 12);
+    assert!(!major.is_symmetric());
+
+    // C, E, G♭, B♭ has the pitch classes of a French sixth and repeats at the tritone
+    let seven_flat_five = Chord::seventh(NoteName::C, SeventhType::Dominant)
+        .remove_interval(Interval::PERFECT_FIFTH)
+        .add_interval(Interval::DIMINISHED_FIFTH);
+    assert_eq!(seven_flat_five.transposition_symmetry(), 6);
+}
+
+/// Builds a chord from a root and interval names such as "M3" or "A11"
+fn chord_from_intervals(root: NoteName, intervals: &[&str]) -> Chord {
+    intervals.iter().fold(Chord::major(root), |chord, name| {
+        chord.add_interval(name.parse().unwrap())
+    })
+}
+
+#[test]
+fn test_polychords() {
+    let lydian = chord_from_intervals(NoteName::C, &["M9", "A11", "M13"]);
+    assert_eq!(
+        lydian.as_polychord(),
+        Some((Chord::major(NoteName::D), Chord::major(NoteName::C)))
+    );
+    assert_eq!(lydian.to_polychord_string().as_deref(), Some("D/C"));
+
+    // The Petrushka chord: C major against F♯ major
+    let petrushka = chord_from_intervals(NoteName::C, &["A4", "A6", "A8"]);
+    let f_sharp = NoteName::new(Letter::F, Accidental::Sharp);
+    assert_eq!(
+        petrushka.as_polychord(),
+        Some((Chord::major(f_sharp), Chord::major(NoteName::C)))
+    );
+
+    let minor_upper = chord_from_intervals(NoteName::C, &["M9", "P11", "M13"]);
+    assert_eq!(minor_upper.to_polychord_string().as_deref(), Some("Dm/C"));
+}
+
+#[test]
+fn test_not_a_polychord() {
+    assert_eq!(
+        Chord::seventh(NoteName::G, SeventhType::Dominant).as_polychord(),
+        None
+    );
+
+    let cluster = chord_from_intervals(NoteName::C, &["m2", "M2", "M6"]);
+    assert_eq!(cluster.as_polychord(), None);
+    assert_eq!(cluster.to_polychord_string(), None);
+}
+
+#[test]
+fn test_figured_bass_triad_inversions() {
+    let c_major = Chord::major(NoteName::C);
+    assert_eq!(c_major.figured_bass(NoteName::C), "5/3");
+    assert_eq!(c_major.figured_bass(NoteName::E), "6");
+    assert_eq!(c_major.figured_bass(NoteName::G), "6/4");
+}
+
+#[test]
+fn test_figured_bass_seventh_inversions() {
+    let g7 = Chord::seventh(NoteName::G, SeventhType::Dominant);
+    assert_eq!(g7.figured_bass(NoteName::G), "7");
+    assert_eq!(g7.figured_bass(NoteName::B), "6/5");
+    assert_eq!(g7.figured_bass(NoteName::D), "4/3");
+    assert_eq!(g7.figured_bass(NoteName::F), "4/2");
+}
+
+#[test]
+fn test_tones_by_stability_dominant_ninth() {
+    let c9 = Chord::seventh(NoteName::C, SeventhType::Dominant).add_interval(Interval::MAJOR_NINTH);
+    let tones = c9.tones_by_stability();
+    let position = |note| tones.iter().position(|&n| n == note).unwrap();
+
+    let b_flat = NoteName::new(Letter::B, Accidental::Flat);
+    assert_eq!(tones.len(), 5);
+    assert_eq!(tones[0], NoteName::C);
+    assert_eq!(tones[1], NoteName::G);
+    for stable in [NoteName::C, NoteName::G] {
+        assert!(position(stable) < position(NoteName::D));
+        assert!(position(stable) < position(b_flat));
+    }
+    assert_eq!(
+        tones,
+        [NoteName::C, NoteName::G, NoteName::E, NoteName::D, b_flat]
+    );
+}
+
+#[test]
+fn test_tones_by_stability_puts_altered_tones_last() {
+    let c7_sharp_9 =
+        Chord::seventh(NoteName::C, SeventhType::Dominant).add_interval(Interval::AUGMENTED_NINTH);
+    let d_sharp = NoteName::new(Letter::D, Accidental::Sharp);
+    assert_eq!(c7_sharp_9.tones_by_stability().last(), Some(&d_sharp));
+}
+
+#[test]
+fn test_is_same_chord_across_inversions() {
+    let c_major = Chord::major(NoteName::C);
+    for n in 0..3 {
+        assert!(c_major.inverted(n).is_same_chord(&c_major));
+    }
+
+    let g7 = Chord::seventh(NoteName::G, SeventhType::Dominant);
+    for n in 0..4 {
+        assert!(g7.inverted(n).is_same_chord(&g7));
+        assert!(
+            !g7.inverted(n)
+                .is_same_chord(&Chord::seventh(NoteName::G, SeventhType::Major))
+        );
+    }
+}
+
+#[test]
+fn test_is_same_chord_across_octave_displacement() {
+    let c_major = Chord::major(NoteName::C);
+    let open = c_major
+        .remove_interval(Interval::MAJOR_THIRD)
+        .add_interval(Interval::MAJOR_THIRD + Interval::PERFECT_OCTAVE);
+    assert_ne!(open, c_major);
+    assert!(open.is_same_chord(&c_major));
+
+    assert!(!c_major.is_same_chord(&Chord::minor(NoteName::C)));
+    assert!(!c_major.is_same_chord(&Chord::major(NoteName::G)));
+}
+
+#[test]
+fn test_from_roman_numeral_with_figures() {
+    let c_major = Key::major(NoteName::C);
+    let parse = |numeral| Chord::from_roman_numeral(numeral, &c_major).unwrap();
+
+    assert_eq!(parse("V"), Chord::major(NoteName::G));
+    assert_eq!(parse("V6"), Chord::major(NoteName::G).inverted(1));
+    assert_eq!(parse("V6/4"), Chord::major(NoteName::G).inverted(2));
+    assert_eq!(
+        parse("V7"),
+        Chord::seventh(NoteName::G, SeventhType::Dominant)
+    );
+
+    let v65 = parse("V6/5");
+    assert_eq!(
+        v65,
+        Chord::seventh(NoteName::G, SeventhType::Dominant).inverted(1)
+    );
+    assert_eq!(v65.notes()[0], NoteName::B);
+    assert_eq!(parse("V65"), v65);
+
+    let ii43 = parse("ii4/3");
+    assert_eq!(
+        ii43,
+        Chord::seventh(NoteName::D, SeventhType::Minor).inverted(2)
+    );
+    assert_eq!(ii43.notes()[0], NoteName::A);
+
+    let v42 = parse("V4/2");
+    assert_eq!(v42.notes()[0], NoteName::F);
+    assert_eq!(parse("V2"), v42);
+
+    assert_eq!(parse("I6"), Chord::major(NoteName::C).inverted(1));
+    assert_eq!(
+        parse("viiø7"),
+        Chord::seventh(NoteName::B, SeventhType::HalfDiminished)
+    );
+    assert_eq!(parse("vii°6"), Chord::diminished(NoteName::B).inverted(1));
+    assert_eq!(
+        parse("IVmaj7"),
+        Chord::seventh(NoteName::F, SeventhType::Major)
+    );
+    assert_eq!(
+        parse("♭VII"),
+        Chord::major(NoteName::new(Letter::B, Accidental::Flat))
+    );
+}
+
+#[test]
+fn test_from_roman_numeral_in_minor_key() {
+    let a_minor = Key::minor(NoteName::A);
+    let parse = |numeral| Chord::from_roman_numeral(numeral, &a_minor).unwrap();
+
+    assert_eq!(parse("i"), Chord::minor(NoteName::A));
+    assert_eq!(parse("iv6"), Chord::minor(NoteName::D).inverted(1));
+    assert_eq!(
+        parse("V7"),
+        Chord::seventh(NoteName::E, SeventhType::Dominant)
+    );
+    let g_sharp = NoteName::new(Letter::G, Accidental::Sharp);
+    assert_eq!(
+        parse("♯vii°7"),
+        Chord::seventh(g_sharp, SeventhType::Diminished)
+    );
+}
+
+#[test]
+fn test_roman_numeral_labels_parse_back() {
+    let e_flat = NoteName::new(Letter::E, Accidental::Flat);
+    for tonic in [NoteName::C, NoteName::G, e_flat] {
+        let key = Key::major(tonic);
+        for (chord, label) in Scale::new(tonic, ScaleType::Major).seventh_chords() {
+            assert_eq!(
+                Chord::from_roman_numeral(&label, &key),
+                Ok(chord),
+                "{}",
+                label
+            );
+        }
+    }
+}
+
+#[test]
+fn test_from_roman_numeral_errors() {
+    let c_major = Key::major(NoteName::C);
+    for numeral in ["", "X", "Vi", "V9", "I6maj", "Imaj6", "iiø6"] {
+        assert_eq!(
+            Chord::from_roman_numeral(numeral, &c_major),
+            Err(chordy::error::ParseError::InvalidRomanNumeral(
+                numeral.to_string()
+            )),
+            "{}",
+            numeral
+        );
+    }
+}
+
+#[test]
+fn test_abbreviated_name_extensions() {
+    let name = |intervals: &[&str]| chord_from_intervals(NoteName::C, intervals).abbreviated_name();
+
+    assert_eq!(name(&["M9"]), "Cadd9");
+    assert_eq!(name(&["M6"]), "C6");
+    assert_eq!(name(&["M6", "M9"]), "C6/9");
+    assert_eq!(name(&["m7", "M9"]), "C9");
+    assert_eq!(name(&["M7", "M9"]), "Cmaj9");
+    assert_eq!(name(&["m7", "M9", "M13"]), "C13");
+    #[cfg(feature = "utf8_symbols")]
+    assert_eq!(name(&["m7", "m9"]), "C7(♭9)");
+    #[cfg(feature = "utf8_symbols")]
+    assert_eq!(name(&["m7", "A9"]), "C7(♯9)");
+    #[cfg(feature = "utf8_symbols")]
+    assert_eq!(name(&["M7", "M9", "A11"]), "Cmaj9(♯11)");
+    assert_eq!(
+        Chord::minor(NoteName::C)
+            .add_interval(Interval::MAJOR_SEVENTH)
+            .abbreviated_name(),
+        "Cm(maj7)"
+    );
+    assert_eq!(
+        Chord::new(NoteName::C, ChordQuality::Sus4)
+            .add_interval(Interval::MINOR_SEVENTH)
+            .abbreviated_name(),
+        "C7sus4"
+    );
+    assert_eq!(
+        Chord::new(NoteName::C, ChordQuality::Sus2).abbreviated_name(),
+        "Csus2"
+    );
+}
+
+#[test]
+fn test_abbreviated_name_omitted_third() {
+    // C, G, B, D
+    let maj9_no3 = Chord::seventh(NoteName::C, SeventhType::Major)
+        .remove_interval(Interval::MAJOR_THIRD)
+        .add_interval(Interval::MAJOR_NINTH);
+    assert_eq!(maj9_no3.omitted_notes(), [OmittedNote::No3]);
+    assert_eq!(maj9_no3.abbreviated_name(), "Cmaj9(no3)");
+
+    let seventh_no3 =
+        Chord::seventh(NoteName::G, SeventhType::Dominant).remove_interval(Interval::MAJOR_THIRD);
+    assert_eq!(seventh_no3.abbreviated_name(), "G7(no3)");
+}
+
+#[test]
+fn test_abbreviated_name_omitted_fifth() {
+    let dominant_no5 =
+        Chord::seventh(NoteName::G, SeventhType::Dominant).remove_interval(Interval::PERFECT_FIFTH);
+    assert_eq!(dominant_no5.omitted_notes(), [OmittedNote::No5]);
+    assert_eq!(dominant_no5.abbreviated_name(), "G7(no5)");
+
+    let thirteenth_no5 = Chord::seventh(NoteName::G, SeventhType::Dominant)
+        .remove_interval(Interval::PERFECT_FIFTH)
+        .add_interval(Interval::MAJOR_NINTH)
+        .add_interval(Interval::MAJOR_THIRTEENTH);
+    assert_eq!(thirteenth_no5.abbreviated_name(), "G13(no5)");
+
+    let shell = Chord::seventh(NoteName::C, SeventhType::Major)
+        .remove_interval(Interval::MAJOR_THIRD)
+        .remove_interval(Interval::PERFECT_FIFTH);
+    assert_eq!(shell.abbreviated_name(), "Cmaj7(no3)(no5)");
+
+    let power = Chord::major(NoteName::C).remove_interval(Interval::MAJOR_THIRD);
+    assert_eq!(power.abbreviated_name(), "C5");
+    assert!(Chord::major(NoteName::C).omitted_notes().is_empty());
+}
+
+#[test]
+fn test_chord_from_str() {
+    let b_flat = NoteName::new(Letter::B, Accidental::Flat);
+    assert_eq!("C".parse(), Ok(Chord::major(NoteName::C)));
+    assert_eq!("Am".parse(), Ok(Chord::minor(NoteName::A)));
+    assert_eq!(
+        "Bbmaj7".parse(),
+        Ok(Chord::seventh(b_flat, SeventhType::Major))
+    );
+    assert_eq!(
+        "B♭maj7".parse(),
+        Ok(Chord::seventh(b_flat, SeventhType::Major))
+    );
+    assert_eq!(
+        "G7".parse(),
+        Ok(Chord::seventh(NoteName::G, SeventhType::Dominant))
+    );
+    assert_eq!(
+        "Bø7".parse(),
+        Ok(Chord::seventh(NoteName::B, SeventhType::HalfDiminished))
+    );
+    assert_eq!(
+        "Bm7b5".parse(),
+        Ok(Chord::seventh(NoteName::B, SeventhType::HalfDiminished))
+    );
+    assert_eq!(
+        "Bdim7".parse(),
+        Ok(Chord::seventh(NoteName::B, SeventhType::Diminished))
+    );
+    assert_eq!(
+        "Dsus4".parse(),
+        Ok(Chord::new(NoteName::D, ChordQuality::Sus4))
+    );
+    assert_eq!(
+        "C7(b9)".parse(),
+        Ok(Chord::seventh(NoteName::C, SeventhType::Dominant).add_interval(Interval::MINOR_NINTH))
+    );
+    assert_eq!("C7(#5#9)".parse::<Chord>(), "C+7(♯9)".parse::<Chord>());
+    assert!("H".parse::<Chord>().is_err());
+    assert!("Cfoo".parse::<Chord>().is_err());
+    assert!("C7(b11)".parse::<Chord>().is_err());
+}
+
+#[test]
+fn test_chord_symbols_round_trip() {
+    let symbols = [
+        "C",
+        "Cm",
+        "C°",
+        "C+",
+        "C5",
+        "Csus2",
+        "Csus4",
+        "C6",
+        "Cm6",
+        "C6/9",
+        "Cadd9",
+        "C7",
+        "Cmaj7",
+        "Cm7",
+        "Cm(maj7)",
+        "Cø7",
+        "C°7",
+        "C+7",
+        "C+maj7",
+        "C7sus4",
+        "C9",
+        "Cmaj9",
+        "Cm9",
+        "C11",
+        "C13",
+        "Cmaj13",
+        "C7(♭9)",
+        "C7(♯9)",
+        "C+7(♯9)",
+        "Cmaj9(♯11)",
+        "C13(♭9)",
+        "Cmaj9(no3)",
+        "G7(no5)",
+        "Cm7(no5)",
+        "C9sus4",
+    ];
+    for symbol in symbols {
+        let chord: Chord = symbol.parse().unwrap();
+        #[cfg(feature = "utf8_symbols")]
+        assert_eq!(chord.abbreviated_name(), symbol);
+        assert_eq!(chord.abbreviated_name().parse(), Ok(chord));
+    }
+}
+
+#[test]
+fn test_voiced_notes_follow_inversion() {
+    use chordy::pitch;
+
+    let c_major = Chord::major(NoteName::C);
+    assert_eq!(
+        c_major.voiced_notes(),
+        [pitch!("C4"), pitch!("E4"), pitch!("G4")]
+    );
+
+    // Still a C major chord, but the voicing puts the third lowest
+    let first = c_major.inverted(1);
+    assert!(first.is_same_chord(&c_major));
+    let voiced = first.voiced_notes();
+    assert_eq!(voiced[0].name(), NoteName::E);
+    assert!(voiced.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let second = c_major.inverted(2).voiced_notes();
+    assert_eq!(second, [pitch!("G4"), pitch!("C5"), pitch!("E5")]);
+}
+
+#[test]
+fn test_shell_voicings() {
+    use chordy::pitch;
+
+    assert_eq!(
+        Chord::seventh(NoteName::C, SeventhType::Major).shell(4),
+        [pitch!("C4"), pitch!("E4"), pitch!("B4")]
+    );
+    assert_eq!(
+        Chord::seventh(NoteName::D, SeventhType::Minor).shell(3),
+        [pitch!("D3"), pitch!("F3"), pitch!("C4")]
+    );
+    assert_eq!(
+        Chord::seventh(NoteName::G, SeventhType::Dominant).shell(2),
+        [pitch!("G2"), pitch!("B2"), pitch!("F3")]
+    );
+
+    // Extensions and the fifth are dropped
+    let c9 = Chord::seventh(NoteName::C, SeventhType::Dominant).add_interval(Interval::MAJOR_NINTH);
+    assert_eq!(c9.shell(4), [pitch!("C4"), pitch!("E4"), pitch!("Bb4")]);
+}
+
+#[test]
+fn test_shell_of_triad_is_full_voicing() {
+    let a_minor = Chord::minor(NoteName::A);
+    assert_eq!(a_minor.shell(3), a_minor.voice(3));
+}
+
+#[test]
+fn test_rootless_voicings() {
+    use chordy::pitch;
+
+    let c9 = Chord::seventh(NoteName::C, SeventhType::Dominant).add_interval(Interval::MAJOR_NINTH);
+    assert_eq!(
+        c9.rootless(4),
+        [pitch!("E4"), pitch!("G4"), pitch!("Bb4"), pitch!("D5")]
+    );
+
+    let d_minor_7 = Chord::seventh(NoteName::D, SeventhType::Minor);
+    assert_eq!(
+        d_minor_7.rootless(3),
+        [pitch!("F3"), pitch!("A3"), pitch!("C4")]
+    );
+
+    // The thirteenth takes the fifth's place
+    let g13 = Chord::seventh(NoteName::G, SeventhType::Dominant)
+        .add_interval(Interval::MAJOR_NINTH)
+        .add_interval(Interval::MAJOR_THIRTEENTH);
+    assert_eq!(
+        g13.rootless(3),
+        [pitch!("B3"), pitch!("E4"), pitch!("F4"), pitch!("A4")]
+    );
+}
+
+#[test]
+fn test_quality_survives_inversion() {
+    let chords = [
+        Chord::major(NoteName::C),
+        Chord::minor(NoteName::D),
+        Chord::diminished(NoteName::B),
+        Chord::augmented(NoteName::E),
+    ];
+    for chord in chords {
+        for n in 1..3 {
+            assert_eq!(chord.inverted(n).quality(), chord.quality(), "{}", chord);
+        }
+    }
 }
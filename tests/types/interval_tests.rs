@@ -0,0 +1,253 @@
+use chordy::error::ParseError;
+use chordy::types::*;
+
+#[test]
+fn test_interval_size() {
+    assert_eq!(Interval::PERFECT_FIFTH.semitones(), 7);
+    assert_eq!(Interval::PERFECT_FIFTH.steps(), 4);
+    assert_eq!(Interval::MINOR_SECOND.semitones(), 1);
+    assert_eq!(Interval::MAJOR_SEVENTH.semitones(), 11);
+    assert_eq!(Interval::AUGMENTED_FOURTH.semitones(), 6);
+    assert_eq!(Interval::DIMINISHED_FIFTH.semitones(), 6);
+    assert_ne!(Interval::AUGMENTED_FOURTH, Interval::DIMINISHED_FIFTH);
+}
+
+#[test]
+fn test_pitch_subtraction_fifth() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    let g4 = Pitch::new(NoteName::new(Letter::G, Accidental::Natural), 4);
+    assert_eq!(g4 - c4, Interval::PERFECT_FIFTH);
+
+    // A fifth that crosses the octave number boundary
+    let a3 = Pitch::new(NoteName::new(Letter::A, Accidental::Natural), 3);
+    let e4 = Pitch::new(NoteName::new(Letter::E, Accidental::Natural), 4);
+    assert_eq!(e4 - a3, Interval::PERFECT_FIFTH);
+}
+
+#[test]
+fn test_pitch_subtraction_octave() {
+    let f_sharp3 = Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 3);
+    let f_sharp4 = Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4);
+    assert_eq!(f_sharp4 - f_sharp3, Interval::PERFECT_OCTAVE);
+    assert_eq!(f_sharp3 - f_sharp3, Interval::PERFECT_UNISON);
+}
+
+#[test]
+fn test_pitch_subtraction_compound() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    let g5 = Pitch::new(NoteName::new(Letter::G, Accidental::Natural), 5);
+    let twelfth = g5 - c4;
+    assert_eq!(twelfth, Interval::PERFECT_FIFTH + Interval::PERFECT_OCTAVE);
+    assert_eq!(twelfth.semitones(), 19);
+    assert_eq!(twelfth.steps(), 11);
+
+    let e5 = Pitch::new(NoteName::new(Letter::E, Accidental::Natural), 5);
+    assert_eq!(e5 - c4, Interval::MAJOR_THIRD + Interval::PERFECT_OCTAVE);
+
+    // Spelling is kept: C4 to D♭5 is a minor ninth, not an augmented octave
+    let d_flat5 = Pitch::new(NoteName::new(Letter::D, Accidental::Flat), 5);
+    assert_eq!(
+        d_flat5 - c4,
+        Interval::MINOR_SECOND + Interval::PERFECT_OCTAVE
+    );
+}
+
+#[test]
+fn test_interval_display() {
+    let cases = [
+        (Interval::PERFECT_UNISON, "P1"),
+        (Interval::MINOR_SECOND, "m2"),
+        (Interval::MAJOR_SECOND, "M2"),
+        (Interval::AUGMENTED_SECOND, "A2"),
+        (Interval::MINOR_THIRD, "m3"),
+        (Interval::MAJOR_THIRD, "M3"),
+        (Interval::PERFECT_FOURTH, "P4"),
+        (Interval::AUGMENTED_FOURTH, "A4"),
+        (Interval::DIMINISHED_FIFTH, "d5"),
+        (Interval::PERFECT_FIFTH, "P5"),
+        (Interval::AUGMENTED_FIFTH, "A5"),
+        (Interval::MINOR_SIXTH, "m6"),
+        (Interval::MAJOR_SIXTH, "M6"),
+        (Interval::AUGMENTED_SIXTH, "A6"),
+        (Interval::DIMINISHED_SEVENTH, "d7"),
+        (Interval::MINOR_SEVENTH, "m7"),
+        (Interval::MAJOR_SEVENTH, "M7"),
+        (Interval::PERFECT_OCTAVE, "P8"),
+        (Interval::MINOR_NINTH, "m9"),
+        (Interval::MAJOR_NINTH, "M9"),
+        (Interval::AUGMENTED_NINTH, "A9"),
+        (Interval::PERFECT_ELEVENTH, "P11"),
+        (Interval::AUGMENTED_ELEVENTH, "A11"),
+        (Interval::MINOR_THIRTEENTH, "m13"),
+        (Interval::MAJOR_THIRTEENTH, "M13"),
+    ];
+    for (interval, name) in cases {
+        assert_eq!(interval.to_string(), name);
+        assert_eq!(name.parse::<Interval>(), Ok(interval));
+    }
+}
+
+#[test]
+fn test_interval_round_trip_up_to_two_octaves() {
+    // Everything from doubly diminished to doubly augmented
+    for fifths in -19i8..=19 {
+        for octaves in -12i8..=12 {
+            let steps = 4 * fifths as i16 + 7 * octaves as i16;
+            let semitones = 7 * fifths as i16 + 12 * octaves as i16;
+            if !(0..=14).contains(&steps) || !(0..=24).contains(&semitones) {
+                continue;
+            }
+            let interval = Interval::new(fifths, octaves);
+            let name = interval.to_string();
+            assert_eq!(name.parse::<Interval>(), Ok(interval), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn test_altered_compound_intervals() {
+    assert_eq!("d12".parse::<Interval>().unwrap().semitones(), 18);
+    assert_eq!("A15".parse::<Interval>().unwrap().semitones(), 25);
+    assert_eq!("dd7".parse::<Interval>().unwrap().semitones(), 8);
+    assert_eq!("AA4".parse::<Interval>().unwrap().semitones(), 7);
+    assert_eq!("d15".parse::<Interval>().unwrap().to_string(), "d15");
+    assert_eq!("-P4".parse::<Interval>().unwrap().semitones(), -5);
+    assert_eq!("-P4".parse::<Interval>().unwrap().to_string(), "-P4");
+}
+
+#[test]
+fn test_invalid_interval_names() {
+    for name in ["", "5", "P", "M5", "P3", "x3", "m0", "Ad3", "P-4"] {
+        assert!(name.parse::<Interval>().is_err(), "{}", name);
+    }
+}
+
+#[test]
+fn test_overflowing_interval_names() {
+    // Heavily altered intervals used to overflow while being parsed
+    let unison = "AAAAA1".parse::<Interval>().unwrap();
+    assert_eq!(unison.semitones(), 5);
+    assert_eq!(unison.simple(), unison);
+    assert_eq!("-ddddd5".parse::<Interval>().unwrap().semitones(), -2);
+
+    // Too many fifths to store in an Interval
+    let a19 = format!("{}1", "A".repeat(19));
+    let d19 = format!("{}4", "d".repeat(19));
+    let a40000 = format!("{}1", "A".repeat(40_000));
+    for name in [&a19, &d19, &a40000, "P127"] {
+        assert_eq!(
+            name.parse::<Interval>(),
+            Err(ParseError::InvalidInterval(name.to_string())),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_add_heavily_augmented_interval() {
+    let b_sharp = NoteName::new(Letter::B, Accidental::Sharp);
+    let a17: Interval = format!("{}1", "A".repeat(17)).parse().unwrap();
+    assert_eq!(a17.fifths(), 119);
+
+    // Seventeen semitones above B♯ is the pitch class of F, spelled E♯
+    let note = b_sharp + a17;
+    assert_eq!(note, NoteName::new(Letter::E, Accidental::Sharp));
+    assert_eq!(note.pitch_class(), (b_sharp.pitch_class() + 17) % 12);
+    assert!((b_sharp + (-a17)).is_enharmonic_with(&NoteName::G));
+}
+
+#[test]
+fn test_equal_tempered_cents() {
+    assert_eq!(Interval::PERFECT_FIFTH.cents(), 700.0);
+    assert_eq!(Interval::MAJOR_THIRD.cents(), 400.0);
+    assert_eq!(Interval::PERFECT_OCTAVE.cents(), 1200.0);
+}
+
+#[test]
+fn test_pythagorean_cents() {
+    let close = |a: f64, b: f64| (a - b).abs() < 0.001;
+    assert!(close(Interval::PERFECT_FIFTH.just_cents(), 701.955));
+    assert!(close(Interval::MAJOR_THIRD.just_cents(), 407.820));
+    assert!(close(Interval::PERFECT_OCTAVE.just_cents(), 1200.0));
+    assert!(close(Interval::PERFECT_FOURTH.just_cents(), 498.045));
+}
+
+#[test]
+fn test_default_interval_is_unison() {
+    assert_eq!(Interval::default(), Interval::PERFECT_UNISON);
+    assert_eq!(Interval::default().semitones(), 0);
+}
+
+#[test]
+fn test_direction_and_directional_display() {
+    let pitch = |note, octave| Pitch::new(note, octave);
+
+    let up_fifth = pitch(NoteName::G, 4) - pitch(NoteName::C, 4);
+    assert_eq!(up_fifth.direction(), IntervalDirection::Ascending);
+    assert_eq!(up_fifth.to_string(), "P5");
+
+    let down_fifth = pitch(NoteName::C, 4) - pitch(NoteName::G, 4);
+    assert_eq!(down_fifth.direction(), IntervalDirection::Descending);
+    assert_eq!(down_fifth.to_string(), "-P5");
+
+    let up_third = pitch(NoteName::E, 4) - pitch(NoteName::C, 4);
+    assert_eq!(up_third.direction(), IntervalDirection::Ascending);
+    assert_eq!(up_third.to_string(), "M3");
+
+    let down_third = pitch(NoteName::C, 4) - pitch(NoteName::E, 4);
+    assert_eq!(down_third.direction(), IntervalDirection::Descending);
+    assert_eq!(down_third.to_string(), "-M3");
+
+    let down_minor_third = Interval::PERFECT_UNISON - Interval::MINOR_THIRD;
+    assert_eq!(down_minor_third.to_string(), "-m3");
+
+    assert_eq!(
+        Interval::PERFECT_UNISON.direction(),
+        IntervalDirection::Unison
+    );
+}
+
+#[test]
+fn test_interval_try_from() {
+    assert_eq!(Interval::try_from("M3"), Ok(Interval::MAJOR_THIRD));
+    assert_eq!(
+        Interval::try_from("P5".to_string()),
+        Ok(Interval::PERFECT_FIFTH)
+    );
+    assert!(Interval::try_from("P3").is_err());
+}
+
+#[test]
+fn test_interval_enharmonic_equivalence() {
+    let tritone_up = Interval::AUGMENTED_FOURTH;
+    assert!(tritone_up.is_enharmonic_with(&Interval::DIMINISHED_FIFTH));
+    assert!(Interval::DIMINISHED_FIFTH.is_enharmonic_with(&tritone_up));
+    assert!(Interval::MAJOR_THIRD.is_enharmonic_with(&Interval::DIMINISHED_FOURTH));
+    assert!(Interval::AUGMENTED_SECOND.is_enharmonic_with(&Interval::MINOR_THIRD));
+
+    assert!(!Interval::MAJOR_THIRD.is_enharmonic_with(&Interval::PERFECT_FOURTH));
+    assert!(
+        !tritone_up.is_enharmonic_with(&(Interval::DIMINISHED_FIFTH + Interval::PERFECT_OCTAVE))
+    );
+    assert!(
+        !Interval::PERFECT_FIFTH
+            .is_enharmonic_with(&(Interval::PERFECT_UNISON - Interval::PERFECT_FIFTH))
+    );
+}
+
+#[test]
+fn test_parse_descending_intervals() {
+    assert_eq!("-P5".parse(), Ok(-Interval::PERFECT_FIFTH));
+    assert_eq!("-m3".parse(), Ok(-Interval::MINOR_THIRD));
+    assert_eq!("-M9".parse(), Ok(-Interval::MAJOR_NINTH));
+    assert_eq!("-P1".parse(), Ok(Interval::PERFECT_UNISON));
+
+    for interval in [Interval::AUGMENTED_FOURTH, Interval::MINOR_SIXTH] {
+        let down = -interval;
+        assert_eq!(down.to_string().parse(), Ok(down));
+        assert_eq!(-down, interval);
+        assert_eq!(down.semitones(), -interval.semitones());
+    }
+    assert!("--P5".parse::<Interval>().is_err());
+}
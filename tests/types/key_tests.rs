@@ -1,4 +1,258 @@
+use chordy::types::*;
+
+fn natural(letter: Letter) -> NoteName {
+    NoteName::new(letter, Accidental::Natural)
+}
+
 #[test]
 fn test_key_creation() {
-    // Will test Key::new()
+    let key = Key::new(natural(Letter::C), Mode::Major);
+    assert_eq!(key.tonic(), natural(Letter::C));
+    assert_eq!(key.mode(), Mode::Major);
+    assert_eq!(key, Key::major(natural(Letter::C)));
+}
+
+#[test]
+fn test_nashville_numbers_diatonic() {
+    let key = Key::major(natural(Letter::C));
+    let chords = [
+        Chord::major(natural(Letter::C)),
+        Chord::major(natural(Letter::F)),
+        Chord::major(natural(Letter::G)),
+        Chord::minor(natural(Letter::A)),
+    ];
+    assert_eq!(key.nashville_numbers(&chords), "1 4 5 6m");
+}
+
+#[test]
+fn test_nashville_numbers_borrowed_chord() {
+    let key = Key::major(natural(Letter::C));
+    let chords = [
+        Chord::major(natural(Letter::C)),
+        Chord::major(NoteName::new(Letter::B, Accidental::Flat)),
+        Chord::minor(natural(Letter::F)),
+        Chord::major(natural(Letter::C)),
+    ];
+    assert_eq!(key.nashville_numbers(&chords), "1 b7 4m 1");
+}
+
+#[test]
+fn test_nashville_numbers_other_key() {
+    let key = Key::major(natural(Letter::D));
+    let chords = [
+        Chord::minor(natural(Letter::E)),
+        Chord::seventh(natural(Letter::A), SeventhType::Dominant),
+        Chord::seventh(natural(Letter::D), SeventhType::Major),
+        Chord::seventh(
+            NoteName::new(Letter::C, Accidental::Sharp),
+            SeventhType::HalfDiminished,
+        ),
+    ];
+    assert_eq!(key.nashville_numbers(&chords), "2m 57 1maj7 7ø7");
+}
+
+#[test]
+fn test_key_accidentals() {
+    assert!(Key::major(natural(Letter::C)).accidentals().is_empty());
+    assert!(Key::minor(natural(Letter::A)).accidentals().is_empty());
+
+    assert_eq!(
+        Key::major(natural(Letter::D)).accidentals(),
+        vec![
+            NoteName::new(Letter::F, Accidental::Sharp),
+            NoteName::new(Letter::C, Accidental::Sharp),
+        ]
+    );
+    assert_eq!(
+        Key::minor(natural(Letter::C)).accidentals(),
+        vec![
+            NoteName::new(Letter::B, Accidental::Flat),
+            NoteName::new(Letter::E, Accidental::Flat),
+            NoteName::new(Letter::A, Accidental::Flat),
+        ]
+    );
+}
+
+#[test]
+fn test_key_accidentals_of_theoretical_keys() {
+    // G♯ major has eight sharps: F is raised twice instead of listed twice
+    let g_sharp = Key::major(NoteName::new(Letter::G, Accidental::Sharp));
+    let accidentals = g_sharp.accidentals();
+    assert_eq!(accidentals.len(), 7);
+    assert_eq!(
+        accidentals[0],
+        NoteName::new(Letter::F, Accidental::DoubleSharp)
+    );
+    assert_eq!(accidentals[6], NoteName::new(Letter::B, Accidental::Sharp));
+
+    // F♭ major has eight flats, starting with B𝄫
+    let f_flat = Key::major(NoteName::new(Letter::F, Accidental::Flat));
+    let accidentals = f_flat.accidentals();
+    assert_eq!(accidentals.len(), 7);
+    assert_eq!(
+        accidentals[0],
+        NoteName::new(Letter::B, Accidental::DoubleFlat)
+    );
+    assert_eq!(accidentals[6], NoteName::new(Letter::F, Accidental::Flat));
+}
+
+#[test]
+fn test_circle_of_fifths() {
+    let tonics: Vec<NoteName> = Key::circle_of_fifths().map(|key| key.tonic()).collect();
+    assert_eq!(
+        tonics,
+        vec![
+            natural(Letter::C),
+            natural(Letter::G),
+            natural(Letter::D),
+            natural(Letter::A),
+            natural(Letter::E),
+            natural(Letter::B),
+            NoteName::new(Letter::F, Accidental::Sharp),
+            NoteName::new(Letter::D, Accidental::Flat),
+            NoteName::new(Letter::A, Accidental::Flat),
+            NoteName::new(Letter::E, Accidental::Flat),
+            NoteName::new(Letter::B, Accidental::Flat),
+            natural(Letter::F),
+        ]
+    );
+    assert!(Key::circle_of_fifths().all(|key| key.mode() == Mode::Major));
+}
+
+#[test]
+fn test_stepping_around_the_circle() {
+    let c_major = Key::major(natural(Letter::C));
+
+    let mut key = c_major;
+    for _ in 0..12 {
+        key = key.next_sharpward();
+    }
+    assert!(key.tonic().is_enharmonic_with(&c_major.tonic()));
+
+    let mut key = c_major;
+    for _ in 0..12 {
+        key = key.next_flatward();
+    }
+    assert!(key.tonic().is_enharmonic_with(&c_major.tonic()));
+
+    let g_flat = Key::major(NoteName::new(Letter::G, Accidental::Flat));
+    assert_eq!(g_flat.next_flatward(), Key::major(natural(Letter::B)));
+    assert_eq!(g_flat.next_sharpward().next_flatward(), g_flat);
+}
+
+#[test]
+fn test_stepping_minor_keys() {
+    let a_minor = Key::minor(natural(Letter::A));
+    assert_eq!(a_minor.next_sharpward(), Key::minor(natural(Letter::E)));
+    assert_eq!(a_minor.next_flatward(), Key::minor(natural(Letter::D)));
+
+    // D♯ minor has six sharps; the next key sharpward is B♭ minor
+    let d_sharp_minor = Key::minor(NoteName::new(Letter::D, Accidental::Sharp));
+    assert_eq!(
+        d_sharp_minor.next_sharpward(),
+        Key::minor(NoteName::new(Letter::B, Accidental::Flat))
+    );
+}
+
+#[test]
+fn test_key_scale() {
+    let g_major = Key::major(natural(Letter::G)).scale();
+    assert_eq!(g_major.tonic(), natural(Letter::G));
+    assert_eq!(
+        g_major.notes(),
+        vec![
+            natural(Letter::G),
+            natural(Letter::A),
+            natural(Letter::B),
+            natural(Letter::C),
+            natural(Letter::D),
+            natural(Letter::E),
+            NoteName::new(Letter::F, Accidental::Sharp),
+        ]
+    );
+
+    let d_minor = Key::minor(natural(Letter::D)).scale();
+    assert_eq!(d_minor.tonic(), natural(Letter::D));
+    assert_eq!(
+        d_minor.notes(),
+        vec![
+            natural(Letter::D),
+            natural(Letter::E),
+            natural(Letter::F),
+            natural(Letter::G),
+            natural(Letter::A),
+            NoteName::new(Letter::B, Accidental::Flat),
+            natural(Letter::C),
+        ]
+    );
+}
+
+#[test]
+fn test_relative_keys() {
+    let pairs = [
+        (natural(Letter::C), natural(Letter::A)),
+        (natural(Letter::G), natural(Letter::E)),
+        (
+            NoteName::new(Letter::E, Accidental::Flat),
+            natural(Letter::C),
+        ),
+        (
+            natural(Letter::A),
+            NoteName::new(Letter::F, Accidental::Sharp),
+        ),
+        (
+            NoteName::new(Letter::G, Accidental::Flat),
+            NoteName::new(Letter::E, Accidental::Flat),
+        ),
+    ];
+
+    for (major, minor) in pairs {
+        assert_eq!(Key::major(major).relative(), Key::minor(minor));
+        assert_eq!(Key::minor(minor).relative(), Key::major(major));
+        assert_eq!(
+            Key::major(major).accidentals(),
+            Key::minor(minor).accidentals()
+        );
+    }
+}
+
+#[test]
+fn test_parallel_keys() {
+    let c_major = Key::major(natural(Letter::C));
+    assert_eq!(c_major.parallel(), Key::minor(natural(Letter::C)));
+    assert_eq!(c_major.parallel().parallel(), c_major);
+
+    let f_sharp_minor = Key::minor(NoteName::new(Letter::F, Accidental::Sharp));
+    assert_eq!(
+        f_sharp_minor.parallel(),
+        Key::major(NoteName::new(Letter::F, Accidental::Sharp))
+    );
+}
+
+#[test]
+fn test_enharmonic_equivalent_keys() {
+    let pairs = [
+        (Letter::B, Accidental::Natural, Letter::C, Accidental::Flat),
+        (Letter::F, Accidental::Sharp, Letter::G, Accidental::Flat),
+        (Letter::C, Accidental::Sharp, Letter::D, Accidental::Flat),
+    ];
+    for (l1, a1, l2, a2) in pairs {
+        let sharp_key = Key::major(NoteName::new(l1, a1));
+        let flat_key = Key::major(NoteName::new(l2, a2));
+        assert_eq!(sharp_key.enharmonic_equivalent(), Some(flat_key));
+        assert_eq!(flat_key.enharmonic_equivalent(), Some(sharp_key));
+    }
+
+    let d_sharp_minor = Key::minor(NoteName::new(Letter::D, Accidental::Sharp));
+    assert_eq!(
+        d_sharp_minor.enharmonic_equivalent(),
+        Some(Key::minor(NoteName::new(Letter::E, Accidental::Flat)))
+    );
+}
+
+#[test]
+fn test_no_enharmonic_equivalent() {
+    assert_eq!(Key::major(natural(Letter::C)).enharmonic_equivalent(), None);
+    assert_eq!(Key::major(natural(Letter::E)).enharmonic_equivalent(), None);
+    assert_eq!(Key::minor(natural(Letter::A)).enharmonic_equivalent(), None);
 }
@@ -1,4 +1,6 @@
+mod chord_symbol_tests;
 mod chord_tests;
+mod interval_tests;
 mod key_tests;
 mod note_name_tests;
 mod pitch_tests;
@@ -1,4 +1,4 @@
-use chordy::types::{Accidental, Letter, NoteName};
+use chordy::types::{Accidental, Interval, Letter, NoteName, Scale, ScaleType};
 
 #[test]
 fn test_note_name_creation() {
@@ -21,3 +21,276 @@ fn test_enharmonic_equivalence() {
     let b_sharp = NoteName::new(Letter::B, Accidental::Sharp);
     assert!(c.is_enharmonic_with(&b_sharp));
 }
+
+#[test]
+fn test_pitch_class() {
+    let pc = |letter, accidental| NoteName::new(letter, accidental).pitch_class();
+
+    assert_eq!(pc(Letter::C, Accidental::Natural), 0);
+    assert_eq!(pc(Letter::E, Accidental::Natural), 4);
+    assert_eq!(pc(Letter::B, Accidental::Natural), 11);
+
+    assert_eq!(pc(Letter::C, Accidental::Sharp), 1);
+    assert_eq!(pc(Letter::D, Accidental::Flat), 1);
+    assert_eq!(pc(Letter::B, Accidental::Sharp), 0);
+    assert_eq!(pc(Letter::C, Accidental::Flat), 11);
+
+    assert_eq!(pc(Letter::B, Accidental::DoubleSharp), 1);
+    assert_eq!(pc(Letter::C, Accidental::DoubleFlat), 10);
+    assert_eq!(pc(Letter::F, Accidental::DoubleSharp), 7);
+}
+
+#[test]
+fn test_letter_distance_to() {
+    let letters = Letter::all();
+    for (i, &from) in letters.iter().enumerate() {
+        for (j, &to) in letters.iter().enumerate() {
+            let from = NoteName::new(from, Accidental::Natural);
+            let to = NoteName::new(to, Accidental::Sharp);
+            assert_eq!(
+                from.letter_distance_to(&to),
+                (j as i8 - i as i8).rem_euclid(7)
+            );
+        }
+    }
+
+    let b = NoteName::new(Letter::B, Accidental::Natural);
+    let c = NoteName::new(Letter::C, Accidental::Flat);
+    assert_eq!(b.letter_distance_to(&c), 1);
+    assert_eq!(c.letter_distance_to(&b), 6);
+    assert_eq!(b.letter_distance_to(&b), 0);
+}
+
+#[test]
+fn test_transpose_diatonic() {
+    let n = |letter| NoteName::new(letter, Accidental::Natural);
+    let c_major = Scale::new(n(Letter::C), ScaleType::Major);
+
+    assert_eq!(
+        n(Letter::E).transpose_diatonic(&c_major, 2),
+        Some(n(Letter::G))
+    );
+    assert_eq!(
+        n(Letter::E).transpose_diatonic(&c_major, -1),
+        Some(n(Letter::D))
+    );
+    assert_eq!(
+        n(Letter::E).transpose_diatonic(&c_major, 0),
+        Some(n(Letter::E))
+    );
+
+    // Wrapping past the octave in either direction
+    assert_eq!(
+        n(Letter::A).transpose_diatonic(&c_major, 3),
+        Some(n(Letter::D))
+    );
+    assert_eq!(
+        n(Letter::D).transpose_diatonic(&c_major, -3),
+        Some(n(Letter::A))
+    );
+    assert_eq!(
+        n(Letter::C).transpose_diatonic(&c_major, 14),
+        Some(n(Letter::C))
+    );
+}
+
+#[test]
+fn test_transpose_diatonic_spelling() {
+    let e_flat_major = Scale::new(NoteName::new(Letter::E, Accidental::Flat), ScaleType::Major);
+
+    // D♯ is found as E♭ and the result uses the scale's flats
+    let d_sharp = NoteName::new(Letter::D, Accidental::Sharp);
+    assert_eq!(
+        d_sharp.transpose_diatonic(&e_flat_major, 1),
+        Some(NoteName::new(Letter::F, Accidental::Natural))
+    );
+    assert_eq!(
+        d_sharp.transpose_diatonic(&e_flat_major, 3),
+        Some(NoteName::new(Letter::A, Accidental::Flat))
+    );
+
+    let e = NoteName::new(Letter::E, Accidental::Natural);
+    assert_eq!(e.transpose_diatonic(&e_flat_major, 1), None);
+}
+
+#[test]
+fn test_defaults() {
+    assert_eq!(Accidental::default(), Accidental::Natural);
+    assert_eq!(
+        NoteName::default(),
+        NoteName::new(Letter::C, Accidental::Natural)
+    );
+}
+
+#[test]
+fn test_natural_note_constants() {
+    let constants = [
+        (NoteName::C, Letter::C, 0),
+        (NoteName::D, Letter::D, 2),
+        (NoteName::E, Letter::E, 4),
+        (NoteName::F, Letter::F, -1),
+        (NoteName::G, Letter::G, 1),
+        (NoteName::A, Letter::A, 3),
+        (NoteName::B, Letter::B, 5),
+    ];
+    for (constant, letter, fifths) in constants {
+        assert_eq!(constant, NoteName::new(letter, Accidental::Natural));
+        assert_eq!(constant.fifths(), fifths);
+    }
+
+    const TONIC: NoteName = NoteName::G;
+    assert_eq!(TONIC.letter(), Letter::G);
+}
+
+#[test]
+fn test_note_name_interval_to() {
+    assert_eq!(NoteName::C.interval_to(&NoteName::E), Interval::MAJOR_THIRD);
+    assert_eq!(NoteName::E.interval_to(&NoteName::C), Interval::MINOR_SIXTH);
+    assert_eq!(
+        NoteName::C.interval_to(&NoteName::C),
+        Interval::PERFECT_UNISON
+    );
+}
+
+#[test]
+fn test_note_name_from_str_and_try_from() {
+    assert_eq!(
+        NoteName::try_from("C#"),
+        Ok(NoteName::new(Letter::C, Accidental::Sharp))
+    );
+    assert_eq!(
+        NoteName::try_from("B♭".to_string()),
+        Ok(NoteName::new(Letter::B, Accidental::Flat))
+    );
+    assert_eq!("E".parse(), Ok(NoteName::E));
+    assert_eq!(
+        NoteName::try_from("H"),
+        Err(chordy::error::ParseError::InvalidNoteName("H".to_string()))
+    );
+    assert!(NoteName::try_from("").is_err());
+    assert!(NoteName::try_from("Cx").is_err());
+}
+
+#[test]
+fn test_frequency_at() {
+    use chordy::{Pitch, note};
+
+    assert!((note!("A").frequency_at(4) - 440.0).abs() < 1e-9);
+    assert_eq!(note!("A").frequency_at_with(4, 415.0), 415.0);
+
+    // MIDI note 69 is the same A4
+    assert_eq!(Pitch::from_midi(69), note!("A").to_pitch(4));
+    assert_eq!(Pitch::from_midi(69).to_frequency(), 440.0);
+    assert_eq!(
+        Pitch::from_midi(69).to_frequency(),
+        note!("A").frequency_at(4)
+    );
+
+    for note in [note!("C"), note!("F#"), note!("Bb"), note!("E##")] {
+        for octave in [-1, 2, 4, 7] {
+            let pitch = Pitch::new(note, octave);
+            assert_eq!(note.to_pitch(octave), pitch);
+            assert_eq!(note.frequency_at(octave), pitch.to_frequency());
+            assert_eq!(
+                note.frequency_at_with(octave, 432.0),
+                pitch.to_frequency_with(432.0)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_accidental_raised_and_lowered() {
+    let all = Accidental::all();
+    for pair in all.windows(2) {
+        assert_eq!(pair[0].raised(), Some(pair[1]));
+        assert_eq!(pair[1].lowered(), Some(pair[0]));
+    }
+    assert_eq!(Accidental::DoubleSharp.raised(), None);
+    assert_eq!(Accidental::DoubleFlat.lowered(), None);
+}
+
+#[test]
+fn test_accidental_from_semitone_offset() {
+    for accidental in Accidental::all() {
+        assert_eq!(
+            Accidental::from_semitone_offset(accidental.semitone_offset()),
+            Some(accidental)
+        );
+    }
+    assert_eq!(Accidental::from_semitone_offset(3), None);
+    assert_eq!(Accidental::from_semitone_offset(-3), None);
+}
+
+#[test]
+fn test_note_name_raised_and_lowered() {
+    let e = |accidental| NoteName::new(Letter::E, accidental);
+    assert_eq!(
+        e(Accidental::DoubleFlat).raised(),
+        Some(e(Accidental::Flat))
+    );
+    assert_eq!(e(Accidental::Flat).raised(), Some(e(Accidental::Natural)));
+    assert_eq!(e(Accidental::Natural).raised(), Some(e(Accidental::Sharp)));
+    assert_eq!(
+        e(Accidental::Sharp).raised(),
+        Some(e(Accidental::DoubleSharp))
+    );
+    assert_eq!(e(Accidental::DoubleSharp).raised(), None);
+
+    assert_eq!(
+        e(Accidental::DoubleSharp).lowered(),
+        Some(e(Accidental::Sharp))
+    );
+    assert_eq!(e(Accidental::Sharp).lowered(), Some(e(Accidental::Natural)));
+    assert_eq!(e(Accidental::Natural).lowered(), Some(e(Accidental::Flat)));
+    assert_eq!(
+        e(Accidental::Flat).lowered(),
+        Some(e(Accidental::DoubleFlat))
+    );
+    assert_eq!(e(Accidental::DoubleFlat).lowered(), None);
+}
+
+#[test]
+fn test_raised_keeps_letter() {
+    // E raised is E♯, not F, even though they sound the same
+    let e_sharp = NoteName::E.raised().unwrap();
+    assert_eq!(e_sharp.letter(), Letter::E);
+    assert!(e_sharp.is_enharmonic_with(&NoteName::F));
+    assert_eq!(NoteName::G.raised().unwrap().pitch_class(), 8);
+}
+
+#[test]
+fn test_note_name_ordered_by_fifths() {
+    use std::collections::BTreeMap;
+
+    assert!(NoteName::C < NoteName::G);
+    assert!(NoteName::G < NoteName::D);
+    assert!(NoteName::F < NoteName::C);
+    assert!(NoteName::B < NoteName::new(Letter::F, Accidental::Sharp));
+    assert!(NoteName::new(Letter::E, Accidental::Flat) < NoteName::F);
+
+    let mut counts = BTreeMap::new();
+    for note in [NoteName::D, NoteName::C, NoteName::G, NoteName::C] {
+        *counts.entry(note).or_insert(0) += 1;
+    }
+    let keys: Vec<NoteName> = counts.keys().copied().collect();
+    assert_eq!(keys, [NoteName::C, NoteName::G, NoteName::D]);
+    assert_eq!(counts[&NoteName::C], 2);
+}
+
+#[test]
+fn test_cmp_by_pitch_class() {
+    use std::cmp::Ordering;
+
+    let c_sharp = NoteName::new(Letter::C, Accidental::Sharp);
+    assert_eq!(NoteName::C.cmp_by_pitch_class(&c_sharp), Ordering::Less);
+    assert_eq!(c_sharp.cmp_by_pitch_class(&NoteName::D), Ordering::Less);
+    assert_eq!(
+        NoteName::B.cmp_by_pitch_class(&NoteName::C),
+        Ordering::Greater
+    );
+    assert_eq!(
+        NoteName::E.cmp_by_pitch_class(&NoteName::E),
+        Ordering::Equal
+    );
+}
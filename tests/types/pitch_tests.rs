@@ -20,11 +20,11 @@ fn test_pitch_creation() {
 
 #[test]
 fn test_midi_number() {
-    let pitch = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 3);
+    let pitch = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
     assert_eq!(pitch.midi_number(), 60);
 
     let pitch = Pitch::new(NoteName::new(Letter::G, Accidental::Sharp), 5);
-    assert_eq!(pitch.midi_number(), 92);
+    assert_eq!(pitch.midi_number(), 80);
 }
 
 #[test]
@@ -34,3 +34,347 @@ fn test_pitch_enharmonic() {
 
     assert!(p1.is_enharmonic_with(&p2));
 }
+
+#[test]
+fn test_pitch_to_abc() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    assert_eq!(Pitch::new(c, 4).to_abc(), "C");
+    assert_eq!(Pitch::new(c, 5).to_abc(), "c");
+    assert_eq!(Pitch::new(c, 6).to_abc(), "c'");
+    assert_eq!(Pitch::new(c, 3).to_abc(), "C,");
+    assert_eq!(Pitch::new(c, 1).to_abc(), "C,,,");
+
+    let g_sharp = NoteName::new(Letter::G, Accidental::Sharp);
+    assert_eq!(Pitch::new(g_sharp, 4).to_abc(), "^G");
+
+    let e_double_flat = NoteName::new(Letter::E, Accidental::DoubleFlat);
+    assert_eq!(Pitch::new(e_double_flat, 5).to_abc(), "__e");
+
+    assert_eq!(
+        Pitch::new(c, i8::MIN).to_abc(),
+        format!("C{}", ",".repeat(132))
+    );
+    assert_eq!(
+        Pitch::new(c, i8::MAX).to_abc(),
+        format!("c{}", "'".repeat(122))
+    );
+}
+
+#[test]
+fn test_pitch_to_lilypond() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    assert_eq!(Pitch::new(c, 3).to_lilypond(), "c");
+    assert_eq!(Pitch::new(c, 4).to_lilypond(), "c'");
+    assert_eq!(Pitch::new(c, 6).to_lilypond(), "c'''");
+    assert_eq!(Pitch::new(c, 2).to_lilypond(), "c,");
+
+    let f_sharp = NoteName::new(Letter::F, Accidental::Sharp);
+    assert_eq!(Pitch::new(f_sharp, 4).to_lilypond(), "fis'");
+
+    let e_flat = NoteName::new(Letter::E, Accidental::Flat);
+    assert_eq!(Pitch::new(e_flat, 3).to_lilypond(), "ees");
+
+    let g_double_sharp = NoteName::new(Letter::G, Accidental::DoubleSharp);
+    assert_eq!(Pitch::new(g_double_sharp, 5).to_lilypond(), "gisis''");
+
+    let b_double_flat = NoteName::new(Letter::B, Accidental::DoubleFlat);
+    assert_eq!(Pitch::new(b_double_flat, 1).to_lilypond(), "beses,,");
+
+    assert_eq!(
+        Pitch::new(c, i8::MIN).to_lilypond(),
+        format!("c{}", ",".repeat(131))
+    );
+    assert_eq!(
+        Pitch::new(c, i8::MAX).to_lilypond(),
+        format!("c{}", "'".repeat(124))
+    );
+}
+
+#[test]
+fn test_pitch_from_midi() {
+    let pitch = Pitch::from_midi(60);
+    assert_eq!(
+        pitch,
+        Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4)
+    );
+
+    let pitch = Pitch::from_midi(0);
+    assert_eq!(pitch.octave(), -1);
+
+    for number in 0..=127 {
+        assert_eq!(Pitch::from_midi(number).midi_number(), number as i16);
+    }
+}
+
+#[test]
+fn test_pitch_range_ascending() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    let c5 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 5);
+
+    let range: Vec<Pitch> = Pitch::range(c4, c5).collect();
+    assert_eq!(range.len(), 13);
+    assert_eq!(range[0], c4);
+    assert_eq!(range[12], c5);
+    for (i, pitch) in range.iter().enumerate() {
+        assert_eq!(pitch.midi_number(), 60 + i as i16);
+    }
+    assert_eq!(
+        range[1],
+        Pitch::new(NoteName::new(Letter::C, Accidental::Sharp), 4)
+    );
+}
+
+#[test]
+fn test_pitch_range_descending() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    let c5 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 5);
+
+    let range: Vec<Pitch> = Pitch::range(c5, c4).collect();
+    assert_eq!(range.len(), 13);
+    assert_eq!(range[0], c5);
+    assert_eq!(range[12], c4);
+    assert_eq!(
+        range[1],
+        Pitch::new(NoteName::new(Letter::B, Accidental::Natural), 4)
+    );
+    assert_eq!(
+        range[2],
+        Pitch::new(NoteName::new(Letter::B, Accidental::Flat), 4)
+    );
+}
+
+#[test]
+fn test_pitch_range_negative_octaves() {
+    let a = Pitch::new(NoteName::new(Letter::A, Accidental::Natural), -2);
+    let d = Pitch::new(NoteName::new(Letter::D, Accidental::Natural), -1);
+
+    let range: Vec<Pitch> = Pitch::range(a, d).collect();
+    assert_eq!(range.len(), 6);
+    assert_eq!(
+        range[3],
+        Pitch::new(NoteName::new(Letter::C, Accidental::Natural), -1)
+    );
+
+    let single: Vec<Pitch> = Pitch::range(a, a).collect();
+    assert_eq!(single, vec![a]);
+}
+
+#[test]
+fn test_transpose_by_interval_tritones() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+
+    let augmented_fourth = c4.transpose_by_interval(Interval::AUGMENTED_FOURTH);
+    assert_eq!(
+        augmented_fourth,
+        Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4)
+    );
+
+    let diminished_fifth = c4.transpose_by_interval(Interval::DIMINISHED_FIFTH);
+    assert_eq!(
+        diminished_fifth,
+        Pitch::new(NoteName::new(Letter::G, Accidental::Flat), 4)
+    );
+
+    assert!(augmented_fourth.is_enharmonic_with(&diminished_fifth));
+    assert_ne!(augmented_fourth, diminished_fifth);
+}
+
+#[test]
+fn test_transpose_by_interval_octaves() {
+    let a3 = Pitch::new(NoteName::new(Letter::A, Accidental::Natural), 3);
+    assert_eq!(
+        a3.transpose_by_interval(Interval::MINOR_THIRD),
+        Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4)
+    );
+    assert_eq!(
+        a3.transpose_by_interval(Interval::MAJOR_SECOND + Interval::PERFECT_OCTAVE),
+        Pitch::new(NoteName::new(Letter::B, Accidental::Natural), 4)
+    );
+
+    let b4 = Pitch::new(NoteName::new(Letter::B, Accidental::Natural), 4);
+    assert_eq!(
+        b4.transpose_by_interval(Interval::AUGMENTED_FOURTH),
+        Pitch::new(NoteName::new(Letter::E, Accidental::Sharp), 5)
+    );
+}
+
+#[test]
+fn test_transpose_saturates_extreme_octaves() {
+    let c127 = Pitch::new(NoteName::C, i8::MAX);
+    assert_eq!(c127.transpose_by_interval(Interval::PERFECT_OCTAVE), c127);
+    assert_eq!(c127.transpose(127), Pitch::new(NoteName::G, i8::MAX));
+
+    let c_lowest = Pitch::new(NoteName::C, i8::MIN);
+    assert_eq!(
+        c_lowest.transpose_by_interval(-Interval::MINOR_SECOND),
+        Pitch::new(NoteName::B, i8::MIN)
+    );
+    assert_eq!(c_lowest.transpose(-128), Pitch::new(NoteName::E, i8::MIN));
+}
+
+#[test]
+fn test_pitch_ordering() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    let c_sharp4 = Pitch::new(NoteName::new(Letter::C, Accidental::Sharp), 4);
+    let d4 = Pitch::new(NoteName::new(Letter::D, Accidental::Natural), 4);
+    let b3 = Pitch::new(NoteName::new(Letter::B, Accidental::Natural), 3);
+
+    assert!(c4 < c_sharp4);
+    assert!(c_sharp4 < d4);
+    assert!(b3 < c4);
+    assert_eq!(c4.max(d4), d4);
+}
+
+#[test]
+fn test_pitch_sorting() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    let c5 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 5);
+    let chromatic: Vec<Pitch> = Pitch::range(c4, c5).collect();
+
+    let mut shuffled: Vec<Pitch> = chromatic.iter().step_by(2).copied().collect();
+    shuffled.extend(chromatic.iter().skip(1).step_by(2).rev());
+    assert_ne!(shuffled, chromatic);
+
+    shuffled.sort();
+    assert_eq!(shuffled, chromatic);
+}
+
+#[test]
+fn test_pitch_ordering_enharmonics() {
+    let c4 = Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4);
+    let b_sharp3 = Pitch::new(NoteName::new(Letter::B, Accidental::Sharp), 3);
+    let d_double_flat4 = Pitch::new(NoteName::new(Letter::D, Accidental::DoubleFlat), 4);
+
+    // Same height, but still different pitches
+    assert_eq!(c4.midi_number(), b_sharp3.midi_number());
+    assert_ne!(c4, b_sharp3);
+    assert_ne!(c4.cmp(&b_sharp3), std::cmp::Ordering::Equal);
+
+    // Flatter spellings sort first
+    let mut pitches = vec![b_sharp3, c4, d_double_flat4];
+    pitches.sort();
+    assert_eq!(pitches, vec![d_double_flat4, c4, b_sharp3]);
+}
+
+#[test]
+fn test_default_pitch_is_middle_c() {
+    let pitch = Pitch::default();
+    assert_eq!(
+        pitch,
+        Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4)
+    );
+    assert_eq!(pitch.to_abc(), "C");
+}
+
+#[test]
+fn test_interval_to() {
+    let c4 = Pitch::new(NoteName::C, 4);
+    let c5 = Pitch::new(NoteName::C, 5);
+    let e5 = Pitch::new(NoteName::E, 5);
+    let g3 = Pitch::new(NoteName::G, 3);
+
+    assert_eq!(c4.interval_to(&c4), Interval::PERFECT_UNISON);
+
+    assert_eq!(c4.interval_to(&c5), Interval::PERFECT_OCTAVE);
+    assert_eq!(c5.interval_to(&c4).to_string(), "-P8");
+
+    let major_tenth = Interval::MAJOR_THIRD + Interval::PERFECT_OCTAVE;
+    assert_eq!(c4.interval_to(&e5), major_tenth);
+    assert_eq!(c4.interval_to(&e5).to_string(), "M10");
+    assert_eq!(e5.interval_to(&c4).to_string(), "-M10");
+
+    assert_eq!(g3.interval_to(&e5).to_string(), "M13");
+    assert_eq!(e5.interval_to(&g3).semitones(), -21);
+}
+
+#[test]
+fn test_pitch_from_str_and_try_from() {
+    assert_eq!(Pitch::try_from("A4"), Ok(Pitch::new(NoteName::A, 4)));
+    assert_eq!(
+        Pitch::try_from("C#5".to_string()),
+        Ok(Pitch::new(NoteName::new(Letter::C, Accidental::Sharp), 5))
+    );
+    assert_eq!(
+        "Bb-1".parse(),
+        Ok(Pitch::new(NoteName::new(Letter::B, Accidental::Flat), -1))
+    );
+    assert!(Pitch::try_from("A").is_err());
+    assert!(Pitch::try_from("H4").is_err());
+    assert!(Pitch::try_from("A4x").is_err());
+}
+
+#[test]
+fn test_midi_range() {
+    assert!(Pitch::new(NoteName::C, -1).is_in_midi_range());
+    assert!(Pitch::new(NoteName::G, 9).is_in_midi_range());
+    assert!(!Pitch::new(NoteName::A, 9).is_in_midi_range());
+    assert!(!Pitch::new(NoteName::new(Letter::C, Accidental::Flat), -1).is_in_midi_range());
+}
+
+#[test]
+fn test_clamp_midi_above_range() {
+    let a9 = Pitch::new(NoteName::A, 9);
+    let clamped = a9.clamp_midi();
+    assert_eq!(clamped, Pitch::new(NoteName::A, 8));
+    assert_eq!(clamped.midi_number(), 117);
+
+    let c20 = Pitch::new(NoteName::C, 20);
+    let clamped = c20.clamp_midi();
+    assert!(clamped.is_in_midi_range());
+    assert_eq!(clamped, Pitch::new(NoteName::C, 9));
+}
+
+#[test]
+fn test_clamp_midi_below_range() {
+    let b_flat = NoteName::new(Letter::B, Accidental::Flat);
+    let low = Pitch::new(b_flat, -4);
+    let clamped = low.clamp_midi();
+    assert_eq!(clamped, Pitch::new(b_flat, -1));
+    assert_eq!(clamped.midi_number(), 10);
+
+    // C♭-1 sits a semitone below MIDI 0
+    let c_flat = NoteName::new(Letter::C, Accidental::Flat);
+    assert_eq!(Pitch::new(c_flat, -1).clamp_midi(), Pitch::new(c_flat, 0));
+
+    let middle_c = Pitch::new(NoteName::C, 4);
+    assert_eq!(middle_c.clamp_midi(), middle_c);
+}
+
+#[test]
+fn test_clamp_midi_extreme_octaves() {
+    let highest = Pitch::new(NoteName::new(Letter::B, Accidental::DoubleSharp), i8::MAX);
+    assert!(highest.midi_number() > 127);
+    let clamped = highest.clamp_midi();
+    assert!(clamped.is_in_midi_range());
+    assert_eq!(clamped.name(), highest.name());
+
+    let lowest = Pitch::new(NoteName::new(Letter::C, Accidental::DoubleFlat), i8::MIN);
+    assert!(lowest.midi_number() < 0);
+    let clamped = lowest.clamp_midi();
+    assert!(clamped.is_in_midi_range());
+    assert_eq!(clamped.name(), lowest.name());
+}
+
+#[test]
+fn test_to_frequency() {
+    assert_eq!(Pitch::new(NoteName::A, 4).to_frequency(), 440.0);
+    assert_eq!(Pitch::new(NoteName::A, 3).to_frequency(), 220.0);
+    assert!((Pitch::new(NoteName::C, 4).to_frequency() - 261.6256).abs() < 1e-4);
+    assert_eq!(Pitch::new(NoteName::A, 4).to_frequency_with(442.0), 442.0);
+}
+
+#[test]
+fn test_cents_to() {
+    use chordy::{cents_between, pitch};
+
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    assert!(close(pitch!("C4").cents_to(&pitch!("C#4")), 100.0));
+    assert!(close(pitch!("C#4").cents_to(&pitch!("C4")), -100.0));
+    assert!(close(pitch!("A3").cents_to(&pitch!("A4")), 1200.0));
+    assert!(close(pitch!("B#3").cents_to(&pitch!("C4")), 0.0));
+
+    // A slightly sharp A4
+    let detuned = cents_between(440.0, 441.0);
+    assert!((detuned - 3.93).abs() < 0.01);
+    assert!(close(cents_between(441.0, 440.0), -detuned));
+}
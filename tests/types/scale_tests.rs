@@ -1,4 +1,6 @@
+use chordy::scales::{self, ScaleDefinition};
 use chordy::types::*;
+use std::collections::HashSet;
 
 #[test]
 fn test_scale_creation() {
@@ -8,3 +10,996 @@ fn test_scale_creation() {
     let notes = scale.notes();
     assert!(notes.contains(&root));
 }
+
+#[test]
+fn test_scale_notes_spelling() {
+    let g = NoteName::new(Letter::G, Accidental::Natural);
+    let notes = Scale::new(g, ScaleType::Major).notes();
+    let names: Vec<String> = notes.iter().map(|n| n.to_string()).collect();
+    #[cfg(feature = "utf8_symbols")]
+    assert_eq!(names, ["G", "A", "B", "C", "D", "E", "F♯"]);
+    #[cfg(not(feature = "utf8_symbols"))]
+    assert_eq!(names, ["G", "A", "B", "C", "D", "E", "F#"]);
+
+    let a = NoteName::new(Letter::A, Accidental::Natural);
+    let notes = Scale::new(a, ScaleType::HarmonicMinor).notes();
+    assert_eq!(notes[6], NoteName::new(Letter::G, Accidental::Sharp));
+}
+
+#[test]
+fn test_degree_of() {
+    let c_major = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::Major,
+    );
+    assert_eq!(
+        c_major.degree_of(&NoteName::new(Letter::C, Accidental::Natural)),
+        Some(1)
+    );
+    assert_eq!(
+        c_major.degree_of(&NoteName::new(Letter::B, Accidental::Natural)),
+        Some(7)
+    );
+    assert_eq!(
+        c_major.degree_of(&NoteName::new(Letter::F, Accidental::Sharp)),
+        None
+    );
+}
+
+#[test]
+fn test_tension_of_each_degree() {
+    let c_major = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::Major,
+    );
+    let expected = [
+        TensionRating::Stable,
+        TensionRating::Tendency,
+        TensionRating::Stable,
+        TensionRating::Tendency,
+        TensionRating::Stable,
+        TensionRating::Tendency,
+        TensionRating::Tendency,
+    ];
+
+    for (note, rating) in c_major.notes().iter().zip(expected) {
+        assert_eq!(c_major.tension(note), rating, "tension of {}", note);
+    }
+}
+
+#[test]
+fn test_tension_of_chromatic_note() {
+    let c_major = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::Major,
+    );
+    let e_flat = NoteName::new(Letter::E, Accidental::Flat);
+    assert_eq!(c_major.tension(&e_flat), TensionRating::Unstable);
+}
+
+#[test]
+fn test_scale_pitches_c_major() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let pitches = Scale::new(c, ScaleType::Major).pitches(4, 1);
+
+    let names: Vec<String> = pitches.iter().map(|p| p.to_string()).collect();
+    assert_eq!(names, ["C4", "D4", "E4", "F4", "G4", "A4", "B4", "C5"]);
+}
+
+#[test]
+fn test_scale_pitches_many_octaves() {
+    let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    let pitches = c_major.pitches(0, 255);
+    assert_eq!(pitches.len(), 128 * 7);
+    assert_eq!(pitches[0], Pitch::new(NoteName::C, 0));
+    assert_eq!(pitches.last(), Some(&Pitch::new(NoteName::B, 127)));
+    assert!(pitches.windows(2).all(|pair| pair[0] < pair[1]));
+
+    assert_eq!(c_major.pitches(0, 128).len(), 128 * 7);
+    assert_eq!(c_major.pitches(120, 10).len(), 8 * 7);
+    assert_eq!(c_major.pitches(127, 0), [Pitch::new(NoteName::C, 127)]);
+}
+
+#[test]
+fn test_scale_pitches_g_major() {
+    let g = NoteName::new(Letter::G, Accidental::Natural);
+    let pitches = Scale::new(g, ScaleType::Major).pitches(3, 2);
+
+    assert_eq!(pitches.len(), 15);
+    assert_eq!(pitches[0], Pitch::new(g, 3));
+    assert_eq!(
+        pitches[3],
+        Pitch::new(NoteName::new(Letter::C, Accidental::Natural), 4)
+    );
+    assert_eq!(
+        pitches[6],
+        Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 4)
+    );
+    assert_eq!(
+        pitches[13],
+        Pitch::new(NoteName::new(Letter::F, Accidental::Sharp), 5)
+    );
+    assert_eq!(pitches[14], Pitch::new(g, 5));
+
+    // The pitches keep rising all the way up
+    for pair in pitches.windows(2) {
+        assert!(pair[0].midi_number() < pair[1].midi_number());
+    }
+}
+
+#[test]
+fn test_secondary_dominants_in_c_major() {
+    let c_major = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::Major,
+    );
+
+    // V7/V
+    assert_eq!(
+        c_major.secondary_dominant(5),
+        Chord::seventh(
+            NoteName::new(Letter::D, Accidental::Natural),
+            SeventhType::Dominant
+        )
+    );
+    // V7/ii
+    assert_eq!(
+        c_major.secondary_dominant(2),
+        Chord::seventh(
+            NoteName::new(Letter::A, Accidental::Natural),
+            SeventhType::Dominant
+        )
+    );
+    // V7/vi, with its G♯ leading tone
+    let e7 = c_major.secondary_dominant(6);
+    assert_eq!(
+        e7,
+        Chord::seventh(
+            NoteName::new(Letter::E, Accidental::Natural),
+            SeventhType::Dominant
+        )
+    );
+    assert!(
+        e7.notes()
+            .contains(&NoteName::new(Letter::G, Accidental::Sharp))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_secondary_dominant_out_of_range() {
+    let c_major = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::Major,
+    );
+    c_major.secondary_dominant(8);
+}
+
+#[test]
+fn test_neapolitan() {
+    let c_minor = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::NaturalMinor,
+    );
+    assert_eq!(
+        c_minor.neapolitan().notes(),
+        vec![
+            NoteName::new(Letter::D, Accidental::Flat),
+            NoteName::new(Letter::F, Accidental::Natural),
+            NoteName::new(Letter::A, Accidental::Flat),
+        ]
+    );
+
+    let a_minor = Scale::new(
+        NoteName::new(Letter::A, Accidental::Natural),
+        ScaleType::HarmonicMinor,
+    );
+    assert_eq!(
+        a_minor.neapolitan(),
+        Chord::major(NoteName::new(Letter::B, Accidental::Flat))
+    );
+
+    // Major keys borrow the same chord
+    let c_major = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::Major,
+    );
+    assert_eq!(c_major.neapolitan(), c_minor.neapolitan());
+}
+
+#[test]
+fn test_negative_harmony_of_diatonic_triads() {
+    let n = |letter, accidental| NoteName::new(letter, accidental);
+    let c_major = Scale::new(n(Letter::C, Accidental::Natural), ScaleType::Major);
+
+    let expected = [
+        Chord::minor(n(Letter::C, Accidental::Natural)),
+        Chord::major(n(Letter::B, Accidental::Flat)),
+        Chord::major(n(Letter::A, Accidental::Flat)),
+        Chord::minor(n(Letter::G, Accidental::Natural)),
+        Chord::minor(n(Letter::F, Accidental::Natural)),
+        Chord::major(n(Letter::E, Accidental::Flat)),
+        Chord::diminished(n(Letter::D, Accidental::Natural)),
+    ];
+    let triads = [
+        Chord::major(n(Letter::C, Accidental::Natural)),
+        Chord::minor(n(Letter::D, Accidental::Natural)),
+        Chord::minor(n(Letter::E, Accidental::Natural)),
+        Chord::major(n(Letter::F, Accidental::Natural)),
+        Chord::major(n(Letter::G, Accidental::Natural)),
+        Chord::minor(n(Letter::A, Accidental::Natural)),
+        Chord::diminished(n(Letter::B, Accidental::Natural)),
+    ];
+
+    for (triad, expected) in triads.iter().zip(expected) {
+        assert_eq!(c_major.negative_harmony(triad), expected);
+    }
+}
+
+#[test]
+fn test_negative_harmony_is_an_involution() {
+    let d = NoteName::new(Letter::D, Accidental::Natural);
+    let d_major = Scale::new(d, ScaleType::Major);
+    let a7 = Chord::seventh(
+        NoteName::new(Letter::A, Accidental::Natural),
+        SeventhType::Dominant,
+    );
+
+    let reflected = d_major.negative_harmony(&a7);
+    assert_ne!(reflected, a7);
+    assert_eq!(d_major.negative_harmony(&reflected), a7);
+}
+
+#[test]
+fn test_harmonic_function() {
+    let c_major = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::Major,
+    );
+    let function = |letter| {
+        c_major.harmonic_function(&Chord::major(NoteName::new(letter, Accidental::Natural)))
+    };
+
+    assert_eq!(function(Letter::C), Some(HarmonicFunction::Tonic));
+    assert_eq!(function(Letter::A), Some(HarmonicFunction::Tonic));
+    assert_eq!(function(Letter::D), Some(HarmonicFunction::Subdominant));
+    assert_eq!(function(Letter::F), Some(HarmonicFunction::Subdominant));
+    assert_eq!(function(Letter::G), Some(HarmonicFunction::Dominant));
+    assert_eq!(function(Letter::B), Some(HarmonicFunction::Dominant));
+
+    let b_flat = Chord::major(NoteName::new(Letter::B, Accidental::Flat));
+    assert_eq!(c_major.harmonic_function(&b_flat), None);
+}
+
+#[test]
+fn test_harmonic_function_outside_heptatonic_scales() {
+    // A is the fifth note of the pentatonic scale, but still the sixth degree
+    let pentatonic = Scale::new(NoteName::C, scales::MAJOR_PENTATONIC);
+    let function = |note| pentatonic.harmonic_function(&Chord::minor(note));
+    assert_eq!(function(NoteName::A), Some(HarmonicFunction::Tonic));
+    assert_eq!(function(NoteName::G), Some(HarmonicFunction::Dominant));
+    assert_eq!(function(NoteName::D), Some(HarmonicFunction::Subdominant));
+
+    // The bebop major scale adds G♯, so A is its seventh note and B its eighth
+    let mut intervals = ScaleDefinition::from(ScaleType::Major).intervals().to_vec();
+    intervals.insert(5, Interval::AUGMENTED_FIFTH);
+    let bebop = Scale::new(
+        NoteName::C,
+        ScaleDefinition::custom("Bebop major", intervals),
+    );
+    assert_eq!(
+        bebop.harmonic_function(&Chord::minor(NoteName::A)),
+        Some(HarmonicFunction::Tonic)
+    );
+    assert_eq!(
+        bebop.harmonic_function(&Chord::diminished(NoteName::B)),
+        Some(HarmonicFunction::Dominant)
+    );
+}
+
+#[test]
+fn test_borrowed_chords() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let c_major = Scale::new(c, ScaleType::Major);
+
+    let f_minor = Chord::minor(NoteName::new(Letter::F, Accidental::Natural));
+    assert_eq!(
+        c_major.is_borrowed(&f_minor),
+        Some(Scale::new(c, scales::AEOLIAN))
+    );
+
+    let b_flat = Chord::major(NoteName::new(Letter::B, Accidental::Flat));
+    assert_eq!(
+        c_major.is_borrowed(&b_flat),
+        Some(Scale::new(c, scales::MIXOLYDIAN))
+    );
+
+    let d_flat = Chord::major(NoteName::new(Letter::D, Accidental::Flat));
+    assert_eq!(
+        c_major.is_borrowed(&d_flat),
+        Some(Scale::new(c, scales::PHRYGIAN))
+    );
+}
+
+#[test]
+fn test_diatonic_and_foreign_chords_are_not_borrowed() {
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let c_major = Scale::new(c, ScaleType::Major);
+
+    let g7 = Chord::seventh(
+        NoteName::new(Letter::G, Accidental::Natural),
+        SeventhType::Dominant,
+    );
+    assert_eq!(c_major.is_borrowed(&g7), None);
+
+    let e_major = Chord::major(NoteName::new(Letter::E, Accidental::Natural));
+    assert_eq!(c_major.is_borrowed(&e_major), None);
+}
+
+#[test]
+fn test_contains_matches_note_list() {
+    let tonics = [
+        NoteName::new(Letter::C, Accidental::Natural),
+        NoteName::new(Letter::F, Accidental::Sharp),
+        NoteName::new(Letter::B, Accidental::Flat),
+        NoteName::new(Letter::E, Accidental::Natural),
+    ];
+
+    for tonic in tonics {
+        for definition in scales::REGISTRY {
+            let scale = Scale::new(tonic, definition.clone());
+            let notes = scale.notes();
+            for letter in Letter::all() {
+                for accidental in Accidental::all() {
+                    let note = NoteName::new(letter, accidental);
+                    assert_eq!(
+                        scale.contains(&note),
+                        notes.iter().any(|n| n.is_enharmonic_with(&note)),
+                        "{} in {} {}",
+                        note,
+                        tonic,
+                        definition.name()
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cached_notes_match_fresh_notes() {
+    for definition in scales::REGISTRY {
+        let scale = Scale::new(
+            NoteName::new(Letter::A, Accidental::Flat),
+            definition.clone(),
+        );
+        assert_eq!(scale.notes_cached(), scale.notes().as_slice());
+        // A second call returns the same cached list
+        assert_eq!(scale.notes_cached(), scale.notes().as_slice());
+    }
+
+    let c = NoteName::new(Letter::C, Accidental::Natural);
+    let cached = Scale::new(c, ScaleType::Major);
+    cached.notes_cached();
+    assert_eq!(cached, Scale::new(c, ScaleType::Major));
+}
+
+#[test]
+fn test_scale_rotation_gives_modes() {
+    let n = |letter| NoteName::new(letter, Accidental::Natural);
+    let c_ionian = Scale::new(n(Letter::C), scales::IONIAN);
+
+    assert_eq!(c_ionian.inverted(0), c_ionian);
+    assert_eq!(
+        c_ionian.inverted(1),
+        Scale::new(n(Letter::D), scales::DORIAN)
+    );
+    assert_eq!(
+        c_ionian.inverted(4),
+        Scale::new(n(Letter::G), scales::MIXOLYDIAN)
+    );
+    assert_eq!(
+        c_ionian.inverted(5),
+        Scale::new(n(Letter::A), scales::AEOLIAN)
+    );
+    assert_eq!(c_ionian.inverted(7), c_ionian);
+    assert_eq!(c_ionian.invert().invert(), c_ionian.inverted(2));
+}
+
+#[test]
+fn test_minor_scale_rotations() {
+    let a = NoteName::new(Letter::A, Accidental::Natural);
+    let harmonic = Scale::new(a, scales::HARMONIC_MINOR);
+    assert_eq!(
+        harmonic.inverted(4),
+        Scale::new(
+            NoteName::new(Letter::E, Accidental::Natural),
+            scales::PHRYGIAN_DOMINANT
+        )
+    );
+
+    let melodic = Scale::new(a, scales::MELODIC_MINOR);
+    let rotated = melodic.inverted(3);
+    assert_eq!(
+        rotated,
+        Scale::new(
+            NoteName::new(Letter::D, Accidental::Natural),
+            scales::LYDIAN_DOMINANT
+        )
+    );
+    assert_eq!(rotated.inverted(4), melodic);
+}
+
+#[test]
+fn test_seventh_chords_c_major() {
+    let c_major = Scale::new(
+        NoteName::new(Letter::C, Accidental::Natural),
+        ScaleType::Major,
+    );
+    let n = |letter| NoteName::new(letter, Accidental::Natural);
+
+    assert_eq!(
+        c_major.seventh_chords(),
+        vec![
+            (
+                Chord::seventh(n(Letter::C), SeventhType::Major),
+                "Imaj7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::D), SeventhType::Minor),
+                "ii7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::E), SeventhType::Minor),
+                "iii7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::F), SeventhType::Major),
+                "IVmaj7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::G), SeventhType::Dominant),
+                "V7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::A), SeventhType::Minor),
+                "vi7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::B), SeventhType::HalfDiminished),
+                "viiø7".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_seventh_chords_a_harmonic_minor() {
+    let a_minor = Scale::new(
+        NoteName::new(Letter::A, Accidental::Natural),
+        ScaleType::HarmonicMinor,
+    );
+    let n = |letter| NoteName::new(letter, Accidental::Natural);
+    let g_sharp = NoteName::new(Letter::G, Accidental::Sharp);
+
+    assert_eq!(
+        a_minor.seventh_chords(),
+        vec![
+            (
+                Chord::seventh(n(Letter::A), SeventhType::MinorMajor),
+                "imaj7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::B), SeventhType::HalfDiminished),
+                "iiø7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::C), SeventhType::AugmentedMajor),
+                "III+maj7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::D), SeventhType::Minor),
+                "iv7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::E), SeventhType::Dominant),
+                "V7".to_string()
+            ),
+            (
+                Chord::seventh(n(Letter::F), SeventhType::Major),
+                "VImaj7".to_string()
+            ),
+            (
+                Chord::seventh(g_sharp, SeventhType::Diminished),
+                "vii°7".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_step_pattern() {
+    let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    assert_eq!(c_major.step_pattern(), [2, 2, 1, 2, 2, 2, 1]);
+
+    // The augmented second between the sixth and seventh degrees
+    let a_harmonic_minor = Scale::new(NoteName::A, ScaleType::HarmonicMinor);
+    assert_eq!(a_harmonic_minor.step_pattern(), [2, 1, 2, 2, 1, 3, 1]);
+
+    let whole_tone = Scale::new(NoteName::C, scales::WHOLE_TONE);
+    assert_eq!(whole_tone.step_pattern(), [2; 6]);
+
+    let e_flat_dorian = Scale::new(
+        NoteName::new(Letter::E, Accidental::Flat),
+        ScaleType::Dorian,
+    );
+    assert_eq!(e_flat_dorian.step_pattern(), [2, 1, 2, 2, 2, 1, 2]);
+    assert_eq!(e_flat_dorian.step_pattern().iter().sum::<u8>(), 12);
+}
+
+#[test]
+fn test_brightness_orders_diatonic_modes() {
+    let brightness = |mode| Scale::new(NoteName::D, mode).brightness();
+    let modes_bright_to_dark = [
+        ScaleType::Lydian,
+        ScaleType::Major,
+        ScaleType::Mixolydian,
+        ScaleType::Dorian,
+        ScaleType::NaturalMinor,
+        ScaleType::Phrygian,
+        ScaleType::Locrian,
+    ];
+
+    for pair in modes_bright_to_dark.windows(2) {
+        assert!(
+            brightness(pair[0]) > brightness(pair[1]),
+            "{:?} should be brighter than {:?}",
+            pair[0],
+            pair[1]
+        );
+    }
+    assert_eq!(brightness(ScaleType::Lydian), 21);
+    assert_eq!(brightness(ScaleType::Dorian), 0);
+    assert_eq!(brightness(ScaleType::Locrian), -21);
+
+    let e_flat = NoteName::new(Letter::E, Accidental::Flat);
+    assert_eq!(
+        Scale::new(e_flat, ScaleType::Dorian).brightness(),
+        brightness(ScaleType::Dorian)
+    );
+}
+
+#[test]
+fn test_complement_c_major() {
+    let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    let complement = c_major.complement();
+
+    assert_eq!(
+        complement,
+        [
+            NoteName::new(Letter::C, Accidental::Sharp),
+            NoteName::new(Letter::E, Accidental::Flat),
+            NoteName::new(Letter::F, Accidental::Sharp),
+            NoteName::new(Letter::G, Accidental::Sharp),
+            NoteName::new(Letter::B, Accidental::Flat),
+        ]
+    );
+    assert!(complement.iter().all(|n| !c_major.contains(n)));
+}
+
+#[test]
+fn test_complement_pentatonic() {
+    let pentatonic = Scale::new(NoteName::C, scales::MAJOR_PENTATONIC);
+    let complement = pentatonic.complement();
+
+    assert_eq!(complement.len(), 7);
+    assert!(complement.iter().all(|n| !pentatonic.contains(n)));
+    assert!(complement.contains(&NoteName::F));
+    assert!(complement.contains(&NoteName::B));
+}
+
+#[test]
+fn test_complement_follows_flat_keys() {
+    let e_flat = NoteName::new(Letter::E, Accidental::Flat);
+    let complement = Scale::new(e_flat, ScaleType::Major).complement();
+    assert!(
+        complement
+            .iter()
+            .all(|n| n.accidental() != Accidental::Sharp)
+    );
+}
+
+#[test]
+fn test_characteristic_notes() {
+    let characteristic = |mode| Scale::new(NoteName::C, mode).characteristic_notes();
+    let flat = |letter| NoteName::new(letter, Accidental::Flat);
+
+    assert_eq!(
+        characteristic(ScaleType::Lydian),
+        [NoteName::new(Letter::F, Accidental::Sharp)]
+    );
+    assert_eq!(characteristic(ScaleType::Dorian), [NoteName::A]);
+    assert_eq!(characteristic(ScaleType::Mixolydian), [flat(Letter::B)]);
+    assert_eq!(characteristic(ScaleType::Phrygian), [flat(Letter::D)]);
+    assert_eq!(
+        characteristic(ScaleType::Locrian),
+        [flat(Letter::D), flat(Letter::G)]
+    );
+    assert_eq!(characteristic(ScaleType::HarmonicMinor), [NoteName::B]);
+
+    assert!(characteristic(ScaleType::Major).is_empty());
+    assert!(characteristic(ScaleType::NaturalMinor).is_empty());
+}
+
+#[test]
+fn test_index_and_get_scale_degrees() {
+    let g_major = Scale::new(NoteName::G, ScaleType::Major);
+
+    assert_eq!(g_major[1], NoteName::G);
+    assert_eq!(g_major[3], NoteName::B);
+    assert_eq!(g_major[7], NoteName::new(Letter::F, Accidental::Sharp));
+
+    assert_eq!(g_major.get(1), Some(NoteName::G));
+    assert_eq!(g_major.get(7), g_major.notes().last().copied());
+    assert_eq!(g_major.get(0), None);
+    assert_eq!(g_major.get(8), None);
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_range() {
+    let g_major = Scale::new(NoteName::G, ScaleType::Major);
+    let _ = g_major[8];
+}
+
+#[test]
+fn test_from_definition_and_custom() {
+    let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    assert_eq!(Scale::from_definition(NoteName::C, scales::IONIAN), c_major);
+
+    let custom = Scale::custom(NoteName::C, "My Major", scales::IONIAN.intervals().to_vec());
+    assert_eq!(custom.definition().name(), "My Major");
+    assert_eq!(custom.notes(), c_major.notes());
+    assert_eq!(custom.definition().bitmask(), scales::IONIAN.bitmask());
+    assert_ne!(custom, c_major);
+
+    let same = Scale::custom(NoteName::C, "My Major", scales::IONIAN.intervals().to_vec());
+    assert_eq!(custom, same);
+}
+
+#[test]
+fn test_custom_scale_modes() {
+    let triad = Scale::custom(
+        NoteName::C,
+        "Triad",
+        vec![
+            Interval::PERFECT_UNISON,
+            Interval::MAJOR_THIRD,
+            Interval::PERFECT_FIFTH,
+        ],
+    );
+    let first_inversion = triad.inverted(1);
+    assert_eq!(first_inversion.tonic(), NoteName::E);
+    assert_eq!(first_inversion.definition().name(), "Triad mode 2");
+    assert_eq!(
+        first_inversion.notes(),
+        vec![NoteName::E, NoteName::G, NoteName::C]
+    );
+    assert_eq!(first_inversion.inverted(2).notes(), triad.notes());
+}
+
+#[test]
+fn test_scale_equals_definition() {
+    let a_minor = Scale::new(NoteName::A, ScaleType::NaturalMinor);
+    assert_eq!(a_minor, scales::AEOLIAN);
+    assert_eq!(scales::AEOLIAN, a_minor);
+    assert_ne!(a_minor, scales::HARMONIC_MINOR);
+
+    let custom = Scale::custom(
+        NoteName::D,
+        "Pentatonic",
+        scales::MAJOR_PENTATONIC.intervals().to_vec(),
+    );
+    assert_eq!(custom, scales::MAJOR_PENTATONIC);
+    assert_eq!(
+        ScaleDefinition::custom("Pentatonic", scales::MAJOR_PENTATONIC.intervals().to_vec()),
+        Scale::new(NoteName::G, scales::MAJOR_PENTATONIC)
+    );
+}
+
+#[test]
+fn test_is_mode_of() {
+    let c_ionian = Scale::new(NoteName::C, ScaleType::Major);
+    let d_dorian = Scale::new(NoteName::D, ScaleType::Dorian);
+    let b_locrian = Scale::new(NoteName::B, ScaleType::Locrian);
+    assert!(d_dorian.is_mode_of(&c_ionian));
+    assert!(c_ionian.is_mode_of(&d_dorian));
+    assert!(b_locrian.is_mode_of(&d_dorian));
+
+    let c_harmonic_minor = Scale::new(NoteName::C, ScaleType::HarmonicMinor);
+    assert!(!d_dorian.is_mode_of(&c_harmonic_minor));
+    assert!(!Scale::new(NoteName::E, ScaleType::Dorian).is_mode_of(&c_ionian));
+    assert!(!c_ionian.is_mode_of(&c_ionian));
+}
+
+#[test]
+fn test_with_raised_degree_derives_minor_scales() {
+    let a_minor = Scale::new(NoteName::A, ScaleType::NaturalMinor);
+
+    let harmonic = a_minor.with_raised_degree(7);
+    assert_eq!(harmonic, Scale::new(NoteName::A, ScaleType::HarmonicMinor));
+    assert_eq!(
+        harmonic.notes()[6],
+        NoteName::new(Letter::G, Accidental::Sharp)
+    );
+
+    let melodic = harmonic.with_raised_degree(6);
+    assert_eq!(melodic, Scale::new(NoteName::A, ScaleType::MelodicMinor));
+    assert_eq!(
+        melodic.notes(),
+        vec![
+            NoteName::A,
+            NoteName::B,
+            NoteName::C,
+            NoteName::D,
+            NoteName::E,
+            NoteName::new(Letter::F, Accidental::Sharp),
+            NoteName::new(Letter::G, Accidental::Sharp),
+        ]
+    );
+}
+
+#[test]
+fn test_with_lowered_degree() {
+    let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    assert_eq!(
+        c_major.with_lowered_degree(7),
+        Scale::new(NoteName::C, ScaleType::Mixolydian)
+    );
+
+    // No built-in scale has these intervals, so a custom one is made
+    let altered = c_major.with_lowered_degree(2);
+    assert_eq!(altered.definition().name(), "Ionian b2");
+    assert_eq!(c_major.with_raised_degree(4).definition().name(), "Lydian");
+    assert_eq!(
+        c_major.with_raised_degree(2).definition().name(),
+        "Ionian #2"
+    );
+    assert_eq!(
+        altered.notes()[1],
+        NoteName::new(Letter::D, Accidental::Flat)
+    );
+    assert_eq!(altered.with_raised_degree(2), c_major);
+}
+
+#[test]
+#[should_panic]
+fn test_with_raised_degree_out_of_range() {
+    Scale::new(NoteName::C, ScaleType::Major).with_raised_degree(8);
+}
+
+#[test]
+fn test_tonic_quality() {
+    let modes = [
+        (ScaleType::Major, ChordQuality::Major),
+        (ScaleType::Dorian, ChordQuality::Minor),
+        (ScaleType::Phrygian, ChordQuality::Minor),
+        (ScaleType::Lydian, ChordQuality::Major),
+        (ScaleType::Mixolydian, ChordQuality::Major),
+        (ScaleType::NaturalMinor, ChordQuality::Minor),
+        (ScaleType::Locrian, ChordQuality::Diminished),
+    ];
+    for (scale_type, quality) in modes {
+        let scale = Scale::new(NoteName::new(Letter::E, Accidental::Flat), scale_type);
+        assert_eq!(scale.tonic_quality(), Some(quality), "{:?}", scale_type);
+    }
+
+    let pentatonic = Scale::new(NoteName::C, scales::MAJOR_PENTATONIC);
+    assert_eq!(pentatonic.tonic_quality(), None);
+}
+
+#[test]
+fn test_chord_at_degree() {
+    let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    assert_eq!(c_major.chord_at_degree(1), Some(Chord::major(NoteName::C)));
+    assert_eq!(
+        c_major.chord_at_degree(7),
+        Some(Chord::diminished(NoteName::B))
+    );
+    assert_eq!(c_major.chord_at_degree(0), None);
+    assert_eq!(c_major.chord_at_degree(8), None);
+}
+
+#[test]
+fn test_chord_qualities() {
+    use ChordQuality::*;
+
+    let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    assert_eq!(
+        c_major.chord_qualities(),
+        [Major, Minor, Minor, Major, Major, Minor, Diminished]
+    );
+
+    let a_harmonic_minor = Scale::new(NoteName::A, ScaleType::HarmonicMinor);
+    assert_eq!(
+        a_harmonic_minor.chord_qualities(),
+        [
+            Minor, Diminished, Augmented, Minor, Major, Major, Diminished
+        ]
+    );
+
+    let whole_tone = Scale::new(NoteName::C, scales::WHOLE_TONE);
+    assert!(whole_tone.chord_qualities().is_empty());
+}
+
+#[test]
+fn test_snap_in_c_major() {
+    use chordy::pitch;
+
+    let c_major = Scale::new(NoteName::C, ScaleType::Major);
+    assert_eq!(c_major.snap(pitch!("C4")), pitch!("C4"));
+    assert_eq!(c_major.snap(pitch!("C#4")), pitch!("D4"));
+    assert_eq!(c_major.snap(pitch!("Eb4")), pitch!("E4"));
+    assert_eq!(c_major.snap(pitch!("F#2")), pitch!("G2"));
+    assert_eq!(c_major.snap(pitch!("Bb5")), pitch!("B5"));
+
+    // Enharmonic inputs take the scale's spelling at the same height
+    assert_eq!(c_major.snap(pitch!("Fb4")), pitch!("E4"));
+    assert_eq!(c_major.snap(pitch!("B#3")), pitch!("C4"));
+}
+
+#[test]
+fn test_snap_in_pentatonic() {
+    use chordy::pitch;
+
+    // C D E G A: the gaps around F and B are a minor third wide
+    let pentatonic = Scale::new(NoteName::C, scales::MAJOR_PENTATONIC);
+    assert_eq!(pentatonic.snap(pitch!("F4")), pitch!("E4"));
+    assert_eq!(pentatonic.snap(pitch!("F#4")), pitch!("G4"));
+    assert_eq!(pentatonic.snap(pitch!("Bb4")), pitch!("A4"));
+    assert_eq!(pentatonic.snap(pitch!("B4")), pitch!("C5"));
+}
+
+#[test]
+fn test_pentatonic_from_heptatonic() {
+    let c_ionian = Scale::new(NoteName::C, ScaleType::Major);
+    let major = c_ionian.pentatonic().unwrap();
+    assert_eq!(
+        major.notes(),
+        vec![
+            NoteName::C,
+            NoteName::D,
+            NoteName::E,
+            NoteName::G,
+            NoteName::A
+        ]
+    );
+
+    let a_aeolian = Scale::new(NoteName::A, ScaleType::NaturalMinor);
+    let minor = a_aeolian.pentatonic().unwrap();
+    assert_eq!(minor, Scale::new(NoteName::A, scales::MINOR_PENTATONIC));
+    assert_eq!(
+        minor.notes(),
+        vec![
+            NoteName::A,
+            NoteName::C,
+            NoteName::D,
+            NoteName::E,
+            NoteName::G
+        ]
+    );
+
+    assert_eq!(
+        Scale::new(NoteName::D, ScaleType::Dorian).pentatonic(),
+        Some(Scale::new(NoteName::D, scales::MINOR_PENTATONIC))
+    );
+}
+
+#[test]
+fn test_pentatonic_not_applicable() {
+    for scale_type in [
+        ScaleType::HarmonicMinor,
+        ScaleType::MelodicMinor,
+        ScaleType::Locrian,
+    ] {
+        assert_eq!(Scale::new(NoteName::C, scale_type).pentatonic(), None);
+    }
+    let pentatonic = Scale::new(NoteName::C, scales::MAJOR_PENTATONIC);
+    assert_eq!(pentatonic.pentatonic(), None);
+
+    // These contain every pentatonic interval but aren't diatonic modes
+    for definition in [scales::LYDIAN_DOMINANT, scales::DORIAN_FLAT_2] {
+        assert_eq!(Scale::new(NoteName::C, definition).pentatonic(), None);
+    }
+}
+
+#[test]
+fn test_from_bitmask_round_trips_built_in_scales() {
+    for definition in scales::REGISTRY {
+        let tonic = NoteName::new(Letter::E, Accidental::Flat);
+        let scale = Scale::new(tonic, definition.clone());
+        let rebuilt = Scale::from_bitmask(tonic, definition.bitmask());
+        assert_eq!(rebuilt.definition().bitmask(), definition.bitmask());
+        assert_eq!(rebuilt, *definition, "{}", definition.name());
+
+        let pitch_classes = |s: &Scale| {
+            s.notes()
+                .iter()
+                .map(|n| n.pitch_class())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            pitch_classes(&rebuilt),
+            pitch_classes(&scale),
+            "{}",
+            definition.name()
+        );
+    }
+
+    for scale_type in [
+        ScaleType::Major,
+        ScaleType::HarmonicMinor,
+        ScaleType::MelodicMinor,
+        ScaleType::Locrian,
+    ] {
+        let scale = Scale::new(NoteName::A, scale_type);
+        let rebuilt = Scale::from_bitmask(NoteName::A, scale.definition().bitmask());
+        assert_eq!(rebuilt.notes(), scale.notes(), "{:?}", scale_type);
+    }
+}
+
+#[test]
+fn test_from_bitmask_avoids_repeated_letters() {
+    use chordy::scales::ScaleBitmask;
+
+    // C D♭ E F G A♭ B: double harmonic, not a built-in scale
+    let mask = ScaleBitmask::new(0b1001_1011_0011);
+    let scale = Scale::from_bitmask(NoteName::C, mask);
+    assert_eq!(scale.definition().name(), "Custom");
+
+    let letters: Vec<Letter> = scale.notes().iter().map(|n| n.letter()).collect();
+    assert_eq!(
+        letters,
+        [
+            Letter::C,
+            Letter::D,
+            Letter::E,
+            Letter::F,
+            Letter::G,
+            Letter::A,
+            Letter::B
+        ]
+    );
+}
+
+#[test]
+fn test_whole_tone_spelling() {
+    let whole_tone = Scale::new(NoteName::C, scales::WHOLE_TONE);
+    let names: Vec<String> = whole_tone
+        .notes()
+        .iter()
+        .map(|n| n.to_string_ascii())
+        .collect();
+    assert_eq!(names, ["C", "D", "E", "F#", "G#", "Bb"]);
+
+    // No letter is used twice
+    let letters: HashSet<Letter> = whole_tone.notes().iter().map(|n| n.letter()).collect();
+    assert_eq!(letters.len(), 6);
+}
+
+#[test]
+fn test_augmented_scale_spelling() {
+    let augmented = Scale::new(NoteName::C, scales::AUGMENTED);
+    let names: Vec<String> = augmented
+        .notes()
+        .iter()
+        .map(|n| n.to_string_ascii())
+        .collect();
+    assert_eq!(names, ["C", "Eb", "E", "G", "Ab", "B"]);
+    assert_eq!(augmented.step_pattern(), [3, 1, 3, 1, 3, 1]);
+
+    let inverse = augmented.inverted(1);
+    assert_eq!(inverse.definition().name(), "Augmented Inverse");
+    assert_eq!(inverse.step_pattern(), [1, 3, 1, 3, 1, 3]);
+    assert_eq!(inverse.inverted(5), augmented);
+}
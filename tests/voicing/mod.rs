@@ -0,0 +1 @@
+mod smooth_voicing_tests;
@@ -0,0 +1,69 @@
+use chordy::types::*;
+use chordy::voicing::{smooth_voicing, voice_leading_cost};
+
+fn names(pitches: &[Pitch]) -> Vec<String> {
+    pitches.iter().map(|p| p.to_string()).collect()
+}
+
+#[test]
+fn test_voice_leading_cost() {
+    let c4 = Pitch::new(NoteName::C, 4);
+    let e4 = Pitch::new(NoteName::E, 4);
+    let g4 = Pitch::new(NoteName::G, 4);
+    let c_major = [c4, e4, g4];
+
+    assert_eq!(voice_leading_cost(&c_major, &c_major), 0);
+    // Order doesn't matter, voices are read from the bottom up
+    assert_eq!(voice_leading_cost(&c_major, &[g4, c4, e4]), 0);
+    // Doubling the root in the octave adds one voice that moves from G
+    let c5 = Pitch::new(NoteName::C, 5);
+    assert_eq!(voice_leading_cost(&c_major, &[c4, e4, g4, c5]), 5);
+    assert_eq!(voice_leading_cost(&[], &c_major), 0);
+}
+
+#[test]
+fn test_smooth_voicing_i_iv_v_i() {
+    let progression = [
+        Chord::major(NoteName::C),
+        Chord::major(NoteName::F),
+        Chord::major(NoteName::G),
+        Chord::major(NoteName::C),
+    ];
+    let voicings = smooth_voicing(&progression, 4);
+
+    assert_eq!(voicings.len(), 4);
+    assert_eq!(names(&voicings[0]), ["C4", "E4", "G4"]);
+    // The common tone C is held while E and G move up by step
+    assert_eq!(names(&voicings[1]), ["C4", "F4", "A4"]);
+    // No common tones between IV and V: every voice moves by step
+    assert_eq!(voice_leading_cost(&voicings[1], &voicings[2]), 6);
+    // G is held into the final tonic
+    let g4 = Pitch::new(NoteName::G, 4);
+    assert!(voicings[2].contains(&g4) && voicings[3].contains(&g4));
+
+    for pair in voicings.windows(2) {
+        for pitch in &pair[1] {
+            let nearest = pair[0]
+                .iter()
+                .map(|p| (p.midi_number() - pitch.midi_number()).abs())
+                .min()
+                .unwrap();
+            assert!(nearest <= 2, "{} leaps away from {:?}", pitch, pair[0]);
+        }
+    }
+}
+
+#[test]
+fn test_smooth_voicing_chords_of_different_sizes() {
+    let progression = [
+        Chord::major(NoteName::C),
+        Chord::seventh(NoteName::G, SeventhType::Dominant),
+        Chord::major(NoteName::C),
+    ];
+    let voicings = smooth_voicing(&progression, 4);
+
+    assert_eq!(voicings[1].len(), 4);
+    assert_eq!(voicings[2].len(), 3);
+    assert!(voice_leading_cost(&voicings[0], &voicings[1]) <= 4);
+    assert!(voice_leading_cost(&voicings[1], &voicings[2]) <= 4);
+}